@@ -1,6 +1,7 @@
 use std::env;
 use std::fmt;
 use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
 
 /// Distinguishes runtime behavior for different stages of the service.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,6 +27,7 @@ pub struct AppConfig {
     pub environment: AppEnvironment,
     pub server: ServerConfig,
     pub telemetry: TelemetryConfig,
+    pub apollo: ApolloConfig,
 }
 
 impl AppConfig {
@@ -43,11 +45,15 @@ impl AppConfig {
             .map_err(|_| ConfigError::InvalidPort)?;
 
         let log_level = env::var("APP_LOG_LEVEL").unwrap_or_else(|_| "info".to_string());
+        let name_map = env::var_os("APOLLO_NAME_MAP")
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from);
 
         Ok(Self {
             environment,
             server: ServerConfig { host, port },
             telemetry: TelemetryConfig { log_level },
+            apollo: ApolloConfig { name_map },
         })
     }
 }
@@ -80,6 +86,13 @@ pub struct TelemetryConfig {
     pub log_level: String,
 }
 
+/// Apollo import settings shared by every request.
+#[derive(Debug, Clone, Default)]
+pub struct ApolloConfig {
+    /// `.csv` or `.toml` file of extra Apollo task names, merged over the built-in map.
+    pub name_map: Option<PathBuf>,
+}
+
 #[derive(Debug)]
 pub enum ConfigError {
     InvalidPort,
@@ -122,6 +135,7 @@ mod tests {
         env::remove_var("APP_HOST");
         env::remove_var("APP_PORT");
         env::remove_var("APP_LOG_LEVEL");
+        env::remove_var("APOLLO_NAME_MAP");
     }
 
     #[test]
@@ -133,6 +147,7 @@ mod tests {
         assert_eq!(config.server.host, "127.0.0.1");
         assert_eq!(config.server.port, 3000);
         assert_eq!(config.telemetry.log_level, "info");
+        assert!(config.apollo.name_map.is_none());
     }
 
     #[test]
@@ -1,6 +1,9 @@
 use crate::config::ConfigError;
 use crate::telemetry::TelemetryError;
 use crate::workflows::apollo::ApolloVacancyImportError;
+use crate::workflows::vacancy::applications::{AlertError, RepositoryError};
+use crate::workflows::vacancy::domain::VacancyError;
+use crate::workflows::vacancy::{CalendarError, RosterError};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
@@ -14,6 +17,11 @@ pub enum AppError {
     Io(std::io::Error),
     Server(axum::Error),
     Workflow(ApolloVacancyImportError),
+    Calendar(CalendarError),
+    Vacancy(VacancyError),
+    Repository(RepositoryError),
+    Roster(RosterError),
+    Alert(AlertError),
 }
 
 impl fmt::Display for AppError {
@@ -24,6 +32,11 @@ impl fmt::Display for AppError {
             AppError::Io(err) => write!(f, "io error: {}", err),
             AppError::Server(err) => write!(f, "server error: {}", err),
             AppError::Workflow(err) => write!(f, "workflow error: {}", err),
+            AppError::Calendar(err) => write!(f, "calendar error: {}", err),
+            AppError::Vacancy(err) => write!(f, "vacancy error: {}", err),
+            AppError::Repository(err) => write!(f, "vacancy repository error: {}", err),
+            AppError::Roster(err) => write!(f, "staff roster error: {}", err),
+            AppError::Alert(err) => write!(f, "alert dispatch error: {}", err),
         }
     }
 }
@@ -36,6 +49,11 @@ impl std::error::Error for AppError {
             AppError::Io(err) => Some(err),
             AppError::Server(err) => Some(err),
             AppError::Workflow(err) => Some(err),
+            AppError::Calendar(err) => Some(err),
+            AppError::Vacancy(err) => Some(err),
+            AppError::Repository(err) => Some(err),
+            AppError::Roster(err) => Some(err),
+            AppError::Alert(err) => Some(err),
         }
     }
 }
@@ -43,7 +61,21 @@ impl std::error::Error for AppError {
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let status = match self {
-            AppError::Workflow(_) => StatusCode::BAD_REQUEST,
+            AppError::Workflow(_) | AppError::Calendar(_) | AppError::Roster(_) => {
+                StatusCode::BAD_REQUEST
+            }
+            AppError::Vacancy(VacancyError::TaskNotFound(_))
+            | AppError::Vacancy(VacancyError::SubtaskNotFound { .. })
+            | AppError::Repository(RepositoryError::NotFound) => StatusCode::NOT_FOUND,
+            AppError::Vacancy(VacancyError::PrerequisitesIncomplete { .. })
+            | AppError::Repository(RepositoryError::Conflict) => StatusCode::CONFLICT,
+            AppError::Vacancy(VacancyError::UnknownBlueprint(_))
+            | AppError::Vacancy(VacancyError::UnknownComplianceNote { .. })
+            | AppError::Vacancy(VacancyError::InvalidSchedule { .. })
+            | AppError::Vacancy(VacancyError::InvalidVacancyId { .. }) => StatusCode::BAD_REQUEST,
+            AppError::Repository(RepositoryError::Unavailable(_)) | AppError::Alert(_) => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
             AppError::Config(_)
             | AppError::Telemetry(_)
             | AppError::Io(_)
@@ -84,3 +116,33 @@ impl From<ApolloVacancyImportError> for AppError {
         Self::Workflow(value)
     }
 }
+
+impl From<CalendarError> for AppError {
+    fn from(value: CalendarError) -> Self {
+        Self::Calendar(value)
+    }
+}
+
+impl From<VacancyError> for AppError {
+    fn from(value: VacancyError) -> Self {
+        Self::Vacancy(value)
+    }
+}
+
+impl From<RepositoryError> for AppError {
+    fn from(value: RepositoryError) -> Self {
+        Self::Repository(value)
+    }
+}
+
+impl From<RosterError> for AppError {
+    fn from(value: RosterError) -> Self {
+        Self::Roster(value)
+    }
+}
+
+impl From<AlertError> for AppError {
+    fn from(value: AlertError) -> Self {
+        Self::Alert(value)
+    }
+}
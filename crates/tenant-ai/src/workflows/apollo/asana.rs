@@ -0,0 +1,101 @@
+use super::parser::ApolloRecord;
+use serde::de::Error as _;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Parses an Asana JSON export or API response: `{"data": [...]}`, `{"data": {...}}` for a
+/// single task, or a bare array of tasks.
+pub(crate) fn parse_records(data: &[u8]) -> Result<Vec<ApolloRecord>, serde_json::Error> {
+    let tasks: Vec<AsanaTask> = match serde_json::from_slice(data)? {
+        Value::Array(tasks) => serde_json::from_value(Value::Array(tasks))?,
+        Value::Object(mut payload) => match payload.remove("data") {
+            Some(Value::Array(tasks)) => serde_json::from_value(Value::Array(tasks))?,
+            Some(task @ Value::Object(_)) => vec![serde_json::from_value(task)?],
+            _ => {
+                return Err(serde_json::Error::custom(
+                    "expected a \"data\" array or object of Asana tasks",
+                ))
+            }
+        },
+        _ => {
+            return Err(serde_json::Error::custom(
+                "expected an Asana task list or {\"data\": ...} payload",
+            ))
+        }
+    };
+
+    Ok(tasks
+        .into_iter()
+        .enumerate()
+        .map(|(index, task)| task.into_record(index + 1))
+        .collect())
+}
+
+#[derive(Debug, Deserialize)]
+struct AsanaTask {
+    #[serde(default)]
+    name: String,
+    /// Reopened tasks can keep a stale `completed_at`; `completed: false` overrides it.
+    #[serde(default)]
+    completed: Option<bool>,
+    #[serde(default)]
+    completed_at: Option<String>,
+    #[serde(default)]
+    created_at: Option<String>,
+    #[serde(default)]
+    modified_at: Option<String>,
+    #[serde(default)]
+    due_on: Option<String>,
+    #[serde(default)]
+    assignee: Option<AsanaNamed>,
+    #[serde(default)]
+    memberships: Vec<AsanaMembership>,
+    #[serde(default)]
+    custom_fields: Vec<AsanaCustomField>,
+}
+
+/// Compact Asana resource; API responses without `opt_fields` may omit the name.
+#[derive(Debug, Deserialize)]
+struct AsanaNamed {
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsanaMembership {
+    #[serde(default)]
+    section: Option<AsanaNamed>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AsanaCustomField {
+    name: String,
+    #[serde(default)]
+    display_value: Option<String>,
+}
+
+impl AsanaTask {
+    fn into_record(self, row: usize) -> ApolloRecord {
+        let mut record = ApolloRecord::new(row, &self.name);
+        let created = record.read_date("created_at", self.created_at.as_deref());
+        let completed = record.read_date("completed_at", self.completed_at.as_deref());
+        let modified = record.read_date("modified_at", self.modified_at.as_deref());
+        let completed = completed.filter(|_| self.completed != Some(false));
+        record.set_timestamps(created, completed, modified);
+        record.due_on = record
+            .read_date("due_on", self.due_on.as_deref())
+            .map(|dt| dt.date());
+        record.assignee = self.assignee.and_then(|assignee| assignee.name);
+        record.sections = self
+            .memberships
+            .into_iter()
+            .filter_map(|membership| membership.section?.name)
+            .collect();
+        record.custom_fields = self
+            .custom_fields
+            .into_iter()
+            .filter_map(|field| Some((field.name, field.display_value?)))
+            .collect();
+        record
+    }
+}
@@ -0,0 +1,112 @@
+use crate::workflows::vacancy::VacancyWorkflowInstance;
+use serde::Serialize;
+
+/// A vacancy imported from Apollo together with what the importer could not use.
+#[derive(Debug)]
+pub struct ApolloImport {
+    pub instance: VacancyWorkflowInstance,
+    pub diagnostics: ApolloImportDiagnostics,
+}
+
+/// Rows the importer skipped or only partly understood. Row numbers are spreadsheet rows,
+/// with the header as row 1, or 1-based task positions for JSON exports.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ApolloImportDiagnostics {
+    pub rows_read: usize,
+    /// Rows whose name matches no known Apollo task, with the closest task when one is
+    /// similar enough to suggest.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmapped_rows: Vec<UnmappedRow>,
+    /// Rows applied through a fuzzy match; worth adding to the name map.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fuzzy_matches: Vec<FuzzyMatch>,
+    /// Rows ignored because another row for the same task won under the duplicate policy.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub duplicate_rows: Vec<DuplicateRow>,
+    /// Tasks left unchanged because their rows disagree; only under
+    /// [`super::DuplicateRowPolicy::ReportConflicts`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicting_rows: Vec<RowConflict>,
+    /// Rows mapped to a task the selected blueprint does not include.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub excluded_rows: Vec<ExcludedRow>,
+    /// Date cells that could not be parsed; the importer treats them as empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invalid_dates: Vec<InvalidDateRow>,
+    /// Blueprint tasks with no matching row anywhere in the export.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_tasks: Vec<MissingTask>,
+}
+
+impl ApolloImportDiagnostics {
+    /// True when every row was used and every blueprint task was found.
+    pub fn is_clean(&self) -> bool {
+        self.unmapped_rows.is_empty()
+            && self.duplicate_rows.is_empty()
+            && self.conflicting_rows.is_empty()
+            && self.excluded_rows.is_empty()
+            && self.invalid_dates.is_empty()
+            && self.missing_tasks.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnmappedRow {
+    pub row: usize,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<TaskSuggestion>,
+}
+
+/// A blueprint task whose name resembles an unmapped row, below the match threshold.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskSuggestion {
+    pub task_key: String,
+    /// Similarity from 0 to 1.
+    pub confidence: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FuzzyMatch {
+    pub row: usize,
+    pub name: String,
+    pub task_key: String,
+    pub confidence: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DuplicateRow {
+    pub row: usize,
+    pub name: String,
+    pub task_key: String,
+    /// Row the duplicate policy chose for the task.
+    pub applied_row: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RowConflict {
+    pub task_key: String,
+    /// Every row mapped to the task, in file order.
+    pub rows: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExcludedRow {
+    pub row: usize,
+    pub name: String,
+    pub task_key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvalidDateRow {
+    pub row: usize,
+    pub name: String,
+    pub column: &'static str,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MissingTask {
+    pub task_key: String,
+    pub name: String,
+}
@@ -8,6 +8,14 @@ pub(crate) fn task_key_for_normalized(normalized_name: &str) -> Option<&'static
     apollo_name_map().get(normalized_name).copied()
 }
 
+/// Known Apollo spellings of `task_key`, normalised.
+pub(crate) fn aliases_for(task_key: &str) -> impl Iterator<Item = &'static str> + '_ {
+    apollo_name_map()
+        .iter()
+        .filter(move |(_, key)| **key == task_key)
+        .map(|(name, _)| name.as_str())
+}
+
 fn apollo_name_map() -> &'static HashMap<String, &'static str> {
     APOLLO_NAME_MAP.get_or_init(|| {
         const NAME_TO_TASK: &[(&str, &str)] = &[
@@ -0,0 +1,174 @@
+use super::mapping;
+use super::normalizer::normalize_name;
+use crate::workflows::vacancy::domain::TaskTemplate;
+use std::collections::BTreeSet;
+
+/// Words that only name who owns a task, e.g. the "Leasing Agent" in
+/// "Create and Publish Listing - Leasing Agent".
+const ROLE_WORDS: &[&str] = &[
+    "accounting",
+    "agent",
+    "coordinator",
+    "compliance",
+    "leasing",
+    "manager",
+    "pm",
+    "property",
+];
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "for", "in", "of", "on", "the", "to", "with",
+];
+
+/// Similarity below which a fuzzy candidate is not worth suggesting.
+pub(crate) const SUGGESTION_FLOOR: f32 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FuzzyCandidate {
+    pub(crate) task_key: String,
+    pub(crate) confidence: f32,
+}
+
+struct CandidateName {
+    task_key: String,
+    tokens: BTreeSet<String>,
+    joined: String,
+}
+
+/// Scores free-form Apollo names against a blueprint's task names and their known aliases.
+pub(crate) struct FuzzyMatcher {
+    candidates: Vec<CandidateName>,
+}
+
+impl FuzzyMatcher {
+    pub(crate) fn new<'a>(templates: impl IntoIterator<Item = &'a TaskTemplate>) -> Self {
+        let mut candidates = Vec::new();
+        for template in templates {
+            let mut names = vec![template.name.as_str()];
+            for alias in mapping::aliases_for(&template.key) {
+                names.push(alias);
+            }
+            for tokens in names.into_iter().flat_map(canonical_forms) {
+                candidates.push(CandidateName {
+                    task_key: template.key.clone(),
+                    joined: join(&tokens),
+                    tokens,
+                });
+            }
+        }
+        Self { candidates }
+    }
+
+    /// Best-scoring task for `name`, if any candidate reaches [`SUGGESTION_FLOOR`].
+    pub(crate) fn best_match(&self, name: &str) -> Option<FuzzyCandidate> {
+        let forms: Vec<(BTreeSet<String>, String)> = canonical_forms(name)
+            .into_iter()
+            .map(|tokens| {
+                let joined = join(&tokens);
+                (tokens, joined)
+            })
+            .collect();
+
+        let mut best: Option<FuzzyCandidate> = None;
+        for candidate in &self.candidates {
+            for (tokens, joined) in &forms {
+                let similarity = token_set_similarity(tokens, &candidate.tokens)
+                    .max(edit_similarity(joined, &candidate.joined));
+                let confidence = (similarity * 100.0).round() / 100.0;
+                if best
+                    .as_ref()
+                    .is_none_or(|current| confidence > current.confidence)
+                {
+                    best = Some(FuzzyCandidate {
+                        task_key: candidate.task_key.clone(),
+                        confidence,
+                    });
+                }
+            }
+        }
+        best.filter(|candidate| candidate.confidence >= SUGGESTION_FLOOR)
+    }
+}
+
+/// Token sets compared for `name`: with a trailing role suffix stripped and, so that a
+/// misspelt suffix still lines up, as written.
+fn canonical_forms(name: &str) -> Vec<BTreeSet<String>> {
+    let normalized = normalize_name(name)
+        .replace(['\u{2013}', '\u{2014}'], "-")
+        .replace('&', " and ");
+    let mut forms = Vec::new();
+    if let Some((head, suffix)) = normalized.rsplit_once(" - ") {
+        if is_role_suffix(suffix) {
+            forms.push(content_tokens(head));
+        }
+    }
+    let full = content_tokens(&normalized);
+    if !forms.contains(&full) {
+        forms.push(full);
+    }
+    forms.retain(|tokens| !tokens.is_empty());
+    forms
+}
+
+/// Lowercased words other than stopwords.
+fn content_tokens(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty() && !STOPWORDS.contains(word))
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_role_suffix(suffix: &str) -> bool {
+    let mut words = suffix
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .peekable();
+    words.peek().is_some() && words.all(|word| ROLE_WORDS.contains(&word))
+}
+
+fn join(tokens: &BTreeSet<String>) -> String {
+    tokens
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Sørensen–Dice coefficient over the two token sets.
+fn token_set_similarity(left: &BTreeSet<String>, right: &BTreeSet<String>) -> f32 {
+    let shared = left.intersection(right).count();
+    (2 * shared) as f32 / (left.len() + right.len()) as f32
+}
+
+/// One minus the Levenshtein distance scaled by the longer string, so typos still score high.
+fn edit_similarity(left: &str, right: &str) -> f32 {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+    let longest = left.len().max(right.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut current = vec![0; right.len() + 1];
+    for (i, left_char) in left.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_char != right_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    1.0 - previous[right.len()] as f32 / longest as f32
+}
+
+#[cfg(test)]
+pub(crate) fn canonical_for_tests(name: &str) -> Vec<String> {
+    canonical_forms(name)
+        .into_iter()
+        .next()
+        .unwrap_or_default()
+        .into_iter()
+        .collect()
+}
@@ -1,23 +1,46 @@
+mod asana;
+mod diagnostics;
 mod mapping;
+mod matcher;
+mod name_map;
 mod normalizer;
+mod options;
 mod parser;
 
+pub use diagnostics::{
+    ApolloImport, ApolloImportDiagnostics, DuplicateRow, ExcludedRow, FuzzyMatch, InvalidDateRow,
+    MissingTask, RowConflict, TaskSuggestion, UnmappedRow,
+};
+pub use name_map::ApolloNameMap;
+pub use options::{ApolloImportOptions, DuplicateRowPolicy};
+pub use parser::ApolloRecord;
+
 use crate::workflows::vacancy::{
-    domain::{TaskStatus, VacancyError},
-    VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+    domain::{StatusChange, TaskStatus, VacancyError},
+    BusinessCalendar, DurationHistory, VacancyWorkflowBlueprint, VacancyWorkflowInstance,
 };
 use chrono::NaiveDate;
-use std::collections::HashSet;
+use std::cmp::Reverse;
+use std::collections::HashMap;
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use parser::ApolloRecord;
+use matcher::FuzzyMatcher;
 
 #[derive(Debug)]
 pub enum ApolloVacancyImportError {
     Io(std::io::Error),
     Csv(csv::Error),
+    Json(serde_json::Error),
     Vacancy(VacancyError),
+    NameMapIo {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    UnsupportedNameMapFormat(PathBuf),
+    NameMapToml(toml::de::Error),
+    /// Name map entries, as `(name, task_key)`, whose task is not in the active blueprint.
+    UnknownNameMapTasks(Vec<(String, String)>),
 }
 
 impl std::fmt::Display for ApolloVacancyImportError {
@@ -25,11 +48,39 @@ impl std::fmt::Display for ApolloVacancyImportError {
         match self {
             ApolloVacancyImportError::Io(err) => write!(f, "failed to read Apollo export: {}", err),
             ApolloVacancyImportError::Csv(err) => write!(f, "invalid Apollo CSV data: {}", err),
+            ApolloVacancyImportError::Json(err) => {
+                write!(f, "invalid Asana JSON export: {}", err)
+            }
             ApolloVacancyImportError::Vacancy(err) => write!(
                 f,
                 "could not apply Apollo data to vacancy workflow: {}",
                 err
             ),
+            ApolloVacancyImportError::NameMapIo { path, source } => write!(
+                f,
+                "failed to read Apollo name map {}: {}",
+                path.display(),
+                source
+            ),
+            ApolloVacancyImportError::UnsupportedNameMapFormat(path) => write!(
+                f,
+                "unsupported Apollo name map format for {} (expected .csv or .toml)",
+                path.display()
+            ),
+            ApolloVacancyImportError::NameMapToml(err) => {
+                write!(f, "invalid Apollo name map TOML: {}", err)
+            }
+            ApolloVacancyImportError::UnknownNameMapTasks(entries) => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(name, task_key)| format!("'{}' -> {}", name, task_key))
+                    .collect();
+                write!(
+                    f,
+                    "Apollo name map targets tasks missing from the blueprint: {}",
+                    entries.join(", ")
+                )
+            }
         }
     }
 }
@@ -39,7 +90,12 @@ impl std::error::Error for ApolloVacancyImportError {
         match self {
             ApolloVacancyImportError::Io(err) => Some(err),
             ApolloVacancyImportError::Csv(err) => Some(err),
+            ApolloVacancyImportError::Json(err) => Some(err),
             ApolloVacancyImportError::Vacancy(err) => Some(err),
+            ApolloVacancyImportError::NameMapIo { source, .. } => Some(source),
+            ApolloVacancyImportError::NameMapToml(err) => Some(err),
+            ApolloVacancyImportError::UnsupportedNameMapFormat(_)
+            | ApolloVacancyImportError::UnknownNameMapTasks(_) => None,
         }
     }
 }
@@ -56,12 +112,20 @@ impl From<csv::Error> for ApolloVacancyImportError {
     }
 }
 
+impl From<serde_json::Error> for ApolloVacancyImportError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
 impl From<VacancyError> for ApolloVacancyImportError {
     fn from(err: VacancyError) -> Self {
         Self::Vacancy(err)
     }
 }
 
+/// Imports Apollo CSV exports and Asana JSON exports or API responses; the format is
+/// detected from the content.
 pub struct ApolloVacancyImporter;
 
 impl ApolloVacancyImporter {
@@ -80,40 +144,327 @@ impl ApolloVacancyImporter {
         target_move_in: NaiveDate,
     ) -> Result<VacancyWorkflowInstance, ApolloVacancyImportError> {
         let blueprint = VacancyWorkflowBlueprint::standard();
-        let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
-        let mut applied: HashSet<&'static str> = HashSet::new();
+        Self::from_reader_with_blueprint(reader, &blueprint, vacancy_start, target_move_in)
+    }
+
+    /// Imports onto a specific program blueprint. Apollo tasks that map to a task the
+    /// blueprint does not include (e.g. LIHTC certification on a market-rate unit) are
+    /// ignored.
+    pub fn from_reader_with_blueprint<R: Read>(
+        reader: R,
+        blueprint: &VacancyWorkflowBlueprint,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+    ) -> Result<VacancyWorkflowInstance, ApolloVacancyImportError> {
+        Self::import_with_blueprint(reader, blueprint, vacancy_start, target_move_in)
+            .map(|import| import.instance)
+    }
+
+    /// Same as [`Self::from_reader`], also returning import diagnostics.
+    pub fn import<R: Read>(
+        reader: R,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+    ) -> Result<ApolloImport, ApolloVacancyImportError> {
+        let blueprint = VacancyWorkflowBlueprint::standard();
+        Self::import_with_blueprint(reader, &blueprint, vacancy_start, target_move_in)
+    }
+
+    /// Same as [`Self::from_reader_with_blueprint`], also reporting the rows that were
+    /// skipped and the blueprint tasks the export never mentions.
+    pub fn import_with_blueprint<R: Read>(
+        reader: R,
+        blueprint: &VacancyWorkflowBlueprint,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+    ) -> Result<ApolloImport, ApolloVacancyImportError> {
+        Self::import_with_options(
+            reader,
+            blueprint,
+            vacancy_start,
+            target_move_in,
+            &ApolloImportOptions::default(),
+        )
+    }
+
+    /// Full import. Each row name is looked up in `options.name_map`, then
+    /// `options.configured_name_map`, then the built-in map; names none of them know are
+    /// fuzzy-matched against the blueprint's task names and applied only when the confidence
+    /// reaches `options.match_threshold`. When several rows land on one task,
+    /// `options.duplicate_policy` decides which of them is applied.
+    pub fn import_with_options<R: Read>(
+        reader: R,
+        blueprint: &VacancyWorkflowBlueprint,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+        options: &ApolloImportOptions,
+    ) -> Result<ApolloImport, ApolloVacancyImportError> {
+        let mut instance = VacancyWorkflowInstance::new(blueprint, vacancy_start, target_move_in);
+        options.name_map.validate(
+            instance
+                .tasks()
+                .iter()
+                .map(|task| task.template.key.as_str()),
+        )?;
+        let matcher = FuzzyMatcher::new(instance.tasks().iter().map(|task| &task.template));
+        let mut diagnostics = ApolloImportDiagnostics::default();
+        let mut groups: Vec<(String, Vec<ApolloRecord>)> = Vec::new();
+        let mut group_index: HashMap<String, usize> = HashMap::new();
+
+        for record in parser::parse_export(reader)? {
+            diagnostics.rows_read += 1;
+            for (column, value) in &record.invalid_dates {
+                diagnostics.invalid_dates.push(InvalidDateRow {
+                    row: record.row,
+                    name: record.name.clone(),
+                    column,
+                    value: value.clone(),
+                });
+            }
 
-        for record in parser::parse_records(reader)? {
-            if let Some(task_key) = mapping::task_key_for_normalized(&record.normalized_name) {
-                if applied.contains(task_key) {
-                    continue;
+            let task_key = match mapped_task_key(options, &record.normalized_name) {
+                Some(task_key) => task_key.to_string(),
+                None => match matcher.best_match(&record.name) {
+                    Some(candidate) if candidate.confidence >= options.match_threshold => {
+                        diagnostics.fuzzy_matches.push(FuzzyMatch {
+                            row: record.row,
+                            name: record.name.clone(),
+                            task_key: candidate.task_key.clone(),
+                            confidence: candidate.confidence,
+                        });
+                        candidate.task_key
+                    }
+                    candidate => {
+                        diagnostics.unmapped_rows.push(UnmappedRow {
+                            row: record.row,
+                            name: record.name,
+                            suggestion: candidate.map(|candidate| TaskSuggestion {
+                                task_key: candidate.task_key,
+                                confidence: candidate.confidence,
+                            }),
+                        });
+                        continue;
+                    }
+                },
+            };
+            let in_blueprint = instance
+                .tasks()
+                .iter()
+                .any(|task| task.template.key == task_key);
+            if !in_blueprint {
+                diagnostics.excluded_rows.push(ExcludedRow {
+                    row: record.row,
+                    name: record.name,
+                    task_key,
+                });
+                continue;
+            }
+            match group_index.get(&task_key) {
+                Some(&index) => groups[index].1.push(record),
+                None => {
+                    group_index.insert(task_key.clone(), groups.len());
+                    groups.push((task_key, vec![record]));
                 }
+            }
+        }
 
-                apply_record(task_key, record, &mut instance, &mut applied)?;
+        for (task_key, mut records) in groups {
+            let Some(chosen) = choose_record(&records, options.duplicate_policy) else {
+                diagnostics.conflicting_rows.push(RowConflict {
+                    rows: records.iter().map(|record| record.row).collect(),
+                    task_key,
+                });
+                continue;
+            };
+            let record = records.remove(chosen);
+            for duplicate in records {
+                diagnostics.duplicate_rows.push(DuplicateRow {
+                    row: duplicate.row,
+                    name: duplicate.name,
+                    task_key: task_key.clone(),
+                    applied_row: record.row,
+                });
             }
+            apply_record(&task_key, record, &mut instance)?;
         }
 
-        Ok(instance)
+        diagnostics.missing_tasks = instance
+            .tasks()
+            .iter()
+            .filter(|task| !group_index.contains_key(task.template.key.as_str()))
+            .map(|task| MissingTask {
+                task_key: task.template.key.clone(),
+                name: task.template.name.clone(),
+            })
+            .collect();
+
+        Ok(ApolloImport {
+            instance,
+            diagnostics,
+        })
+    }
+
+    /// Parses an export without applying it, keeping the assignee, due date, sections,
+    /// and custom fields each task carries.
+    pub fn records<R: Read>(reader: R) -> Result<Vec<ApolloRecord>, ApolloVacancyImportError> {
+        parser::parse_export(reader)
+    }
+
+    pub fn duration_history_from_path<P: AsRef<Path>>(
+        path: P,
+        calendar: &BusinessCalendar,
+    ) -> Result<DurationHistory, ApolloVacancyImportError> {
+        let file = std::fs::File::open(path)?;
+        Self::duration_history(file, calendar)
     }
+
+    /// Learns task durations from a past Apollo export: each recognised row with both
+    /// Created At and Completed At contributes the business days between them on
+    /// `calendar`, the unit the forecast simulates in.
+    pub fn duration_history<R: Read>(
+        reader: R,
+        calendar: &BusinessCalendar,
+    ) -> Result<DurationHistory, ApolloVacancyImportError> {
+        let blueprint = VacancyWorkflowBlueprint::standard();
+        Self::duration_history_with_options(
+            reader,
+            &blueprint,
+            calendar,
+            &ApolloImportOptions::default(),
+        )
+    }
+
+    /// Same as [`Self::duration_history`], recognising row names exactly as
+    /// [`Self::import_with_options`] does with the same `blueprint` and `options`, so a
+    /// history export and a live export in one request resolve to the same tasks.
+    pub fn duration_history_with_options<R: Read>(
+        reader: R,
+        blueprint: &VacancyWorkflowBlueprint,
+        calendar: &BusinessCalendar,
+        options: &ApolloImportOptions,
+    ) -> Result<DurationHistory, ApolloVacancyImportError> {
+        options.name_map.validate(
+            blueprint
+                .task_templates()
+                .iter()
+                .map(|task| task.key.as_str()),
+        )?;
+        let matcher = FuzzyMatcher::new(blueprint.task_templates().iter());
+        let mut history = DurationHistory::new();
+        for record in parser::parse_export(reader)? {
+            let (Some(created_on), Some(completed_on)) = (record.created_on, record.completed_on)
+            else {
+                continue;
+            };
+            let task_key = match mapped_task_key(options, &record.normalized_name) {
+                Some(task_key) => task_key.to_string(),
+                None => match matcher.best_match(&record.name) {
+                    Some(candidate) if candidate.confidence >= options.match_threshold => {
+                        candidate.task_key
+                    }
+                    _ => continue,
+                },
+            };
+            let days = calendar
+                .business_days_between(created_on, completed_on)
+                .max(0);
+            history.record(&task_key, days as u32);
+        }
+        Ok(history)
+    }
+}
+
+/// Task key for a row name from the request map, then the configured map, then the
+/// built-in map; `None` leaves the row to fuzzy matching.
+fn mapped_task_key<'a>(options: &'a ApolloImportOptions, normalized_name: &str) -> Option<&'a str> {
+    options
+        .name_map
+        .task_key_for_normalized(normalized_name)
+        .or_else(|| {
+            options
+                .configured_name_map
+                .task_key_for_normalized(normalized_name)
+        })
+        .or_else(|| mapping::task_key_for_normalized(normalized_name))
+}
+
+/// Index of the row to apply among rows for one task, or `None` when the policy reports
+/// the rows as conflicting.
+fn choose_record(records: &[ApolloRecord], policy: DuplicateRowPolicy) -> Option<usize> {
+    match policy {
+        DuplicateRowPolicy::LatestModified => newest_with_progress(records),
+        DuplicateRowPolicy::PreferCompleted => records
+            .iter()
+            .enumerate()
+            .max_by_key(|(index, record)| {
+                (progress_rank(record), record.last_modified, Reverse(*index))
+            })
+            .map(|(index, _)| index),
+        DuplicateRowPolicy::ReportConflicts => {
+            let mut outcomes: Vec<(TaskStatus, Option<NaiveDate>)> =
+                records.iter().filter_map(imported_status).collect();
+            outcomes.dedup();
+            if outcomes.len() > 1 {
+                return None;
+            }
+            newest_with_progress(records)
+        }
+    }
+}
+
+/// Row with the newest Last Modified among rows that carry progress, or among all rows
+/// when none do. Rows without a Last Modified rank oldest and the earlier row wins a tie,
+/// as it did before duplicate policies existed.
+fn newest_with_progress(records: &[ApolloRecord]) -> Option<usize> {
+    let any_progress = records
+        .iter()
+        .any(|record| imported_status(record).is_some());
+    records
+        .iter()
+        .enumerate()
+        .filter(|(_, record)| !any_progress || imported_status(record).is_some())
+        .max_by_key(|(index, record)| (record.last_modified, Reverse(*index)))
+        .map(|(index, _)| index)
 }
 
+fn progress_rank(record: &ApolloRecord) -> u8 {
+    match imported_status(record) {
+        Some((TaskStatus::Completed, _)) => 2,
+        Some(_) => 1,
+        None => 0,
+    }
+}
+
+/// Status a row reports for its task; `None` when the row carries no progress.
+fn imported_status(record: &ApolloRecord) -> Option<(TaskStatus, Option<NaiveDate>)> {
+    if let Some(completed_on) = record.completed_on {
+        Some((TaskStatus::Completed, Some(completed_on)))
+    } else if record.touched {
+        Some((TaskStatus::InProgress, None))
+    } else {
+        None
+    }
+}
+
+/// Applies the row's status to its task, if it carries any progress.
 fn apply_record(
-    task_key: &'static str,
+    task_key: &str,
     record: ApolloRecord,
     instance: &mut VacancyWorkflowInstance,
-    applied: &mut HashSet<&'static str>,
 ) -> Result<(), VacancyError> {
-    if let Some(completed_on) = record.completed_on {
-        instance.set_status(task_key, TaskStatus::Completed, Some(completed_on))?;
-        applied.insert(task_key);
-    } else if record.touched {
-        instance.set_status(task_key, TaskStatus::InProgress, None)?;
-        applied.insert(task_key);
+    if let Some((status, completed_on)) = imported_status(&record) {
+        instance.record_status(task_key, imported_change(status, completed_on))?;
     }
-
     Ok(())
 }
 
+fn imported_change(status: TaskStatus, completed_on: Option<NaiveDate>) -> StatusChange {
+    StatusChange {
+        note: Some("Synced from Apollo export".to_string()),
+        ..StatusChange::new(status, completed_on)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,6 +500,21 @@ mod tests {
         assert!(parser::parse_datetime_for_tests("not-a-date").is_none());
     }
 
+    #[test]
+    fn export_format_detects_json_past_bom_and_whitespace() {
+        use parser::ExportFormat;
+        assert_eq!(
+            ExportFormat::detect("\u{feff}\n  {\"data\": []}".as_bytes()),
+            ExportFormat::AsanaJson
+        );
+        assert_eq!(ExportFormat::detect(b"[]"), ExportFormat::AsanaJson);
+        assert_eq!(
+            ExportFormat::detect("\u{feff}Task ID,Name\n".as_bytes()),
+            ExportFormat::Csv
+        );
+        assert_eq!(ExportFormat::detect(b""), ExportFormat::Csv);
+    }
+
     #[test]
     fn normalize_name_removes_whitespace_and_case() {
         let source = "\u{feff}Create  and  Publish  Listing  -  Leasing  Agent";
@@ -222,6 +588,20 @@ Create and Publish Listing - Leasing Agent,2025-09-24T11:00:00Z,,2025-09-24T12:3
         }
     }
 
+    #[test]
+    fn fuzzy_canonical_form_drops_role_suffix_and_punctuation() {
+        assert_eq!(
+            matcher::canonical_for_tests(
+                "Manage Inquiries & Schedule Showings \u{2013} Leasing Agent"
+            ),
+            vec!["inquiries", "manage", "schedule", "showings"]
+        );
+        assert_eq!(
+            matcher::canonical_for_tests("Collect Move-In Funds - PM"),
+            vec!["collect", "funds", "move"]
+        );
+    }
+
     #[test]
     fn mapping_recognizes_known_tasks() {
         assert_eq!(
@@ -0,0 +1,130 @@
+use super::normalizer::normalize_name;
+use super::ApolloVacancyImportError;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::Path;
+
+/// User-supplied Apollo task names and the blueprint task each one means. Entries are
+/// checked before the built-in map, so they can also redirect a name it already knows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApolloNameMap {
+    /// Keyed by normalized name; holds the name as written and its task key.
+    entries: BTreeMap<String, (String, String)>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NameMapRow {
+    name: String,
+    task_key: String,
+}
+
+impl ApolloNameMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a `.csv` (`name,task_key` header) or `.toml` (`"Apollo name" = "task_key"`) file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, ApolloVacancyImportError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        let read_error = |source| ApolloVacancyImportError::NameMapIo {
+            path: path.to_path_buf(),
+            source,
+        };
+        match extension.as_deref() {
+            Some("csv") => Self::from_csv_reader(std::fs::File::open(path).map_err(read_error)?),
+            Some("toml") => {
+                Self::from_toml_str(&std::fs::read_to_string(path).map_err(read_error)?)
+            }
+            _ => Err(ApolloVacancyImportError::UnsupportedNameMapFormat(
+                path.to_path_buf(),
+            )),
+        }
+    }
+
+    pub fn from_csv_reader<R: Read>(reader: R) -> Result<Self, ApolloVacancyImportError> {
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let mut map = Self::new();
+        for row in csv_reader.deserialize::<NameMapRow>() {
+            let row = row?;
+            map.insert(&row.name, &row.task_key);
+        }
+        Ok(map)
+    }
+
+    pub fn from_toml_str(source: &str) -> Result<Self, ApolloVacancyImportError> {
+        let entries: BTreeMap<String, String> =
+            toml::from_str(source).map_err(ApolloVacancyImportError::NameMapToml)?;
+        Ok(entries.iter().collect())
+    }
+
+    /// Adds or replaces the entry for `name`.
+    pub fn insert(&mut self, name: &str, task_key: &str) {
+        self.entries.insert(
+            normalize_name(name),
+            (name.trim().to_string(), task_key.trim().to_string()),
+        );
+    }
+
+    /// Layers `other` over this map; its entries win on the same name.
+    pub fn merge(&mut self, other: &ApolloNameMap) {
+        for (normalized, entry) in &other.entries {
+            self.entries.insert(normalized.clone(), entry.clone());
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// `(name, task_key)` pairs in name order.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .values()
+            .map(|(name, task_key)| (name.as_str(), task_key.as_str()))
+    }
+
+    pub(crate) fn task_key_for_normalized(&self, normalized_name: &str) -> Option<&str> {
+        self.entries
+            .get(normalized_name)
+            .map(|(_, task_key)| task_key.as_str())
+    }
+
+    /// Fails with every entry whose task key is not one of `task_keys`.
+    pub fn validate<'a>(
+        &self,
+        task_keys: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), ApolloVacancyImportError> {
+        let known: Vec<&str> = task_keys.into_iter().collect();
+        let unknown: Vec<(String, String)> = self
+            .entries()
+            .filter(|(_, task_key)| !known.contains(task_key))
+            .map(|(name, task_key)| (name.to_string(), task_key.to_string()))
+            .collect();
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(ApolloVacancyImportError::UnknownNameMapTasks(unknown))
+        }
+    }
+}
+
+impl<N: AsRef<str>, K: AsRef<str>> FromIterator<(N, K)> for ApolloNameMap {
+    fn from_iter<I: IntoIterator<Item = (N, K)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (name, task_key) in iter {
+            map.insert(name.as_ref(), task_key.as_ref());
+        }
+        map
+    }
+}
@@ -0,0 +1,60 @@
+use super::ApolloNameMap;
+use serde::{Deserialize, Serialize};
+
+/// Tunes how [`super::ApolloVacancyImporter`] resolves Apollo rows to blueprint tasks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApolloImportOptions {
+    /// Minimum fuzzy-match confidence (0–1) for a name missing from the built-in map to be
+    /// applied. Weaker matches are reported as suggestions; above 1.0 only suggests.
+    pub match_threshold: f32,
+    /// Extra name to task key entries, checked before the built-in map. Every entry must
+    /// target a task in the import's blueprint.
+    pub name_map: ApolloNameMap,
+    /// Site-wide entries (e.g. from `APOLLO_NAME_MAP`), checked after `name_map` and before
+    /// the built-in map. They are shared across programs, so like built-in entries, a row
+    /// they send to a task outside the blueprint is reported in `excluded_rows`.
+    pub configured_name_map: ApolloNameMap,
+    /// Which row wins when several rows map to the same task.
+    pub duplicate_policy: DuplicateRowPolicy,
+}
+
+impl ApolloImportOptions {
+    pub const DEFAULT_MATCH_THRESHOLD: f32 = 0.9;
+}
+
+impl Default for ApolloImportOptions {
+    fn default() -> Self {
+        Self {
+            match_threshold: Self::DEFAULT_MATCH_THRESHOLD,
+            name_map: ApolloNameMap::default(),
+            configured_name_map: ApolloNameMap::default(),
+            duplicate_policy: DuplicateRowPolicy::default(),
+        }
+    }
+}
+
+/// How the importer picks between rows that map to the same task, e.g. a task that was
+/// reopened as a new Apollo task and completed later.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateRowPolicy {
+    /// The row with the newest Last Modified, skipping rows that carry no progress unless
+    /// none do; the earlier row wins ties.
+    #[default]
+    LatestModified,
+    /// A completed row over an in-progress one over an untouched one, then the newest.
+    PreferCompleted,
+    /// Leave the task alone and report the rows when they disagree on its status.
+    ReportConflicts,
+}
+
+impl DuplicateRowPolicy {
+    pub fn from_key(value: &str) -> Option<Self> {
+        match value.trim() {
+            "latest_modified" => Some(Self::LatestModified),
+            "prefer_completed" => Some(Self::PreferCompleted),
+            "report_conflicts" => Some(Self::ReportConflicts),
+            _ => None,
+        }
+    }
+}
@@ -1,13 +1,109 @@
 use super::normalizer::normalize_name;
+use super::ApolloVacancyImportError;
 use chrono::{DateTime, NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
 use std::io::Read;
 
-#[derive(Debug)]
-pub(crate) struct ApolloRecord {
+/// One task from an Apollo CSV or Asana JSON export.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApolloRecord {
+    /// Spreadsheet row number with the header as row 1; for JSON, the 1-based position in
+    /// the task list.
+    pub row: usize,
+    pub name: String,
     pub(crate) normalized_name: String,
-    pub(crate) completed_on: Option<NaiveDate>,
+    pub created_on: Option<NaiveDate>,
+    pub completed_on: Option<NaiveDate>,
+    pub last_modified: Option<NaiveDateTime>,
     pub(crate) touched: bool,
+    /// Assignee display name.
+    pub assignee: Option<String>,
+    pub due_on: Option<NaiveDate>,
+    /// Board sections or columns holding the task.
+    pub sections: Vec<String>,
+    /// Custom field display values by field name; only JSON exports carry them.
+    pub custom_fields: BTreeMap<String, String>,
+    /// `(column, value)` pairs holding text that is not a recognised date.
+    pub(crate) invalid_dates: Vec<(&'static str, String)>,
+}
+
+impl ApolloRecord {
+    pub(crate) fn new(row: usize, name: &str) -> Self {
+        Self {
+            row,
+            name: name.trim().to_string(),
+            normalized_name: normalize_name(name),
+            created_on: None,
+            completed_on: None,
+            last_modified: None,
+            touched: false,
+            assignee: None,
+            due_on: None,
+            sections: Vec::new(),
+            custom_fields: BTreeMap::new(),
+            invalid_dates: Vec::new(),
+        }
+    }
+
+    /// Parses a date cell, noting it under `column` when it holds something unreadable.
+    pub(crate) fn read_date(
+        &mut self,
+        column: &'static str,
+        value: Option<&str>,
+    ) -> Option<NaiveDateTime> {
+        let value = value.filter(|value| !value.trim().is_empty())?;
+        let parsed = parse_datetime(value);
+        if parsed.is_none() {
+            self.invalid_dates.push((column, value.to_string()));
+        }
+        parsed
+    }
+
+    /// Fills the lifecycle dates; a task modified after it was created counts as touched.
+    pub(crate) fn set_timestamps(
+        &mut self,
+        created: Option<NaiveDateTime>,
+        completed: Option<NaiveDateTime>,
+        modified: Option<NaiveDateTime>,
+    ) {
+        self.created_on = created.map(|dt| dt.date());
+        self.completed_on = completed.map(|dt| dt.date());
+        self.last_modified = modified;
+        self.touched =
+            matches!((created, modified), (Some(created), Some(modified)) if modified > created);
+    }
+}
+
+/// Shape of an export, decided from its first meaningful character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExportFormat {
+    Csv,
+    AsanaJson,
+}
+
+impl ExportFormat {
+    pub(crate) fn detect(data: &[u8]) -> Self {
+        match strip_bom(data)
+            .iter()
+            .find(|byte| !byte.is_ascii_whitespace())
+        {
+            Some(b'{' | b'[') => Self::AsanaJson,
+            _ => Self::Csv,
+        }
+    }
+}
+
+/// Reads a whole export and parses it as CSV or Asana JSON, whichever it looks like.
+pub(crate) fn parse_export<R: Read>(
+    mut reader: R,
+) -> Result<Vec<ApolloRecord>, ApolloVacancyImportError> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    match ExportFormat::detect(&data) {
+        ExportFormat::Csv => Ok(parse_records(data.as_slice())?),
+        ExportFormat::AsanaJson => Ok(super::asana::parse_records(strip_bom(&data))?),
+    }
 }
 
 pub(crate) fn parse_records<R: Read>(reader: R) -> Result<Vec<ApolloRecord>, csv::Error> {
@@ -16,17 +112,19 @@ pub(crate) fn parse_records<R: Read>(reader: R) -> Result<Vec<ApolloRecord>, csv
         .from_reader(reader);
     let mut records = Vec::new();
 
-    for record in csv_reader.deserialize::<ApolloRow>() {
+    for (index, record) in csv_reader.deserialize::<ApolloRow>().enumerate() {
         let row = record?;
-        let normalized_name = normalize_name(&row.name);
-        let completed_on = row.completed_date();
-        let touched = row.touched();
-
-        records.push(ApolloRecord {
-            normalized_name,
-            completed_on,
-            touched,
-        });
+        let mut record = ApolloRecord::new(index + 2, &row.name);
+        let created = record.read_date("Created At", row.created_at.as_deref());
+        let completed = record.read_date("Completed At", row.completed_at.as_deref());
+        let modified = record.read_date("Last Modified", row.last_modified.as_deref());
+        record.set_timestamps(created, completed, modified);
+        record.due_on = record
+            .read_date("Due Date", row.due_date.as_deref())
+            .map(|dt| dt.date());
+        record.assignee = row.assignee;
+        record.sections = row.section.into_iter().collect();
+        records.push(record);
     }
 
     Ok(records)
@@ -54,25 +152,24 @@ struct ApolloRow {
         deserialize_with = "empty_string_as_none"
     )]
     last_modified: Option<String>,
-}
-
-impl ApolloRow {
-    fn completed_date(&self) -> Option<NaiveDate> {
-        self.completed_at
-            .as_deref()
-            .and_then(parse_datetime)
-            .map(|dt| dt.date())
-    }
-
-    fn touched(&self) -> bool {
-        match (
-            self.created_at.as_deref().and_then(parse_datetime),
-            self.last_modified.as_deref().and_then(parse_datetime),
-        ) {
-            (Some(created), Some(modified)) => modified > created,
-            _ => false,
-        }
-    }
+    #[serde(
+        rename = "Due Date",
+        default,
+        deserialize_with = "empty_string_as_none"
+    )]
+    due_date: Option<String>,
+    #[serde(
+        rename = "Assignee",
+        default,
+        deserialize_with = "empty_string_as_none"
+    )]
+    assignee: Option<String>,
+    #[serde(
+        rename = "Section/Column",
+        default,
+        deserialize_with = "empty_string_as_none"
+    )]
+    section: Option<String>,
 }
 
 fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
@@ -83,6 +180,10 @@ where
     Ok(opt.filter(|value| !value.trim().is_empty()))
 }
 
+fn strip_bom(data: &[u8]) -> &[u8] {
+    data.strip_prefix("\u{feff}".as_bytes()).unwrap_or(data)
+}
+
 fn parse_datetime(value: &str) -> Option<NaiveDateTime> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -24,7 +24,7 @@ pub use domain::{
 pub use evaluation::{ApplicationDecision, DenialReason, EvaluationConfig, EvaluationOutcome};
 pub use repository::{
     AlertError, AlertPublisher, AppFolioAlert, ApplicationRecord, ApplicationRepository,
-    ApplicationStatusView, RepositoryError,
+    ApplicationStatusView, RepositoryError, VacancyAlert,
 };
 pub use router::application_router;
 pub use service::{ApplicationServiceError, VacancyApplicationService};
@@ -53,6 +53,10 @@ pub enum RepositoryError {
 /// Trait describing outbound alert hooks (e.g., AppFolio or e-mail adapters).
 pub trait AlertPublisher: Send + Sync {
     fn publish(&self, alert: AppFolioAlert) -> Result<(), AlertError>;
+
+    /// Publishes vacancy automation raised by the
+    /// [`AutomationDispatcher`](crate::workflows::vacancy::AutomationDispatcher).
+    fn publish_vacancy(&self, alert: VacancyAlert) -> Result<(), AlertError>;
 }
 
 /// Simple alert payload so routes/tests can assert integration boundaries.
@@ -63,6 +67,15 @@ pub struct AppFolioAlert {
     pub details: BTreeMap<String, String>,
 }
 
+/// AppFolio alert raised for a tracked vacancy rather than an application.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VacancyAlert {
+    pub template: String,
+    /// Tracked vacancy in `PROPERTY:UNIT` form.
+    pub vacancy_id: String,
+    pub details: BTreeMap<String, String>,
+}
+
 /// Alert dispatch error.
 #[derive(Debug, thiserror::Error)]
 pub enum AlertError {
@@ -16,7 +16,7 @@ use crate::workflows::vacancy::applications::domain::{
 use crate::workflows::vacancy::applications::evaluation::EvaluationEngine;
 use crate::workflows::vacancy::applications::repository::{
     AlertError, AlertPublisher, AppFolioAlert, ApplicationRecord, ApplicationRepository,
-    RepositoryError,
+    RepositoryError, VacancyAlert,
 };
 use crate::workflows::vacancy::applications::{
     application_router, EvaluationConfig, VacancyApplicationService,
@@ -206,6 +206,10 @@ impl AlertPublisher for MemoryAlerts {
             .push(alert);
         Ok(())
     }
+
+    fn publish_vacancy(&self, _alert: VacancyAlert) -> Result<(), AlertError> {
+        Ok(())
+    }
 }
 
 pub(super) struct ConflictRepository;
@@ -0,0 +1,302 @@
+use super::applications::{AlertError, AlertPublisher, VacancyAlert};
+use super::domain::{ComplianceSeverity, TaskStatus, VacancyRole};
+use super::instance::VacancyWorkflowInstance;
+use super::report::views::VacancyReportSummary;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// An automation the platform can carry out for a vacancy, naming the tasks it concerns
+/// and the staff ids to notify. Empty `recipients` means the role's shared queue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AutomationTrigger {
+    /// Remind the owners of a role's open tasks that are no longer waiting on prerequisites.
+    RemindRole {
+        role: VacancyRole,
+        task_keys: Vec<String>,
+        recipients: Vec<String>,
+    },
+    /// Hand compliance notes at Warning or above to the compliance coordinator.
+    EscalateToCoordinator {
+        task_keys: Vec<String>,
+        topics: Vec<String>,
+        recipients: Vec<String>,
+    },
+    /// Push overdue tasks into AppFolio task queues.
+    DispatchAppFolioAlert {
+        task_keys: Vec<String>,
+        recipients: Vec<String>,
+    },
+}
+
+impl AutomationTrigger {
+    /// AppFolio alert template used when the trigger is dispatched.
+    pub const fn template(&self) -> &'static str {
+        match self {
+            Self::RemindRole { .. } => "vacancy_task_reminder",
+            Self::EscalateToCoordinator { .. } => "vacancy_compliance_escalation",
+            Self::DispatchAppFolioAlert { .. } => "vacancy_overdue_tasks",
+        }
+    }
+
+    pub fn task_keys(&self) -> &[String] {
+        match self {
+            Self::RemindRole { task_keys, .. }
+            | Self::EscalateToCoordinator { task_keys, .. }
+            | Self::DispatchAppFolioAlert { task_keys, .. } => task_keys,
+        }
+    }
+
+    pub fn recipients(&self) -> &[String] {
+        match self {
+            Self::RemindRole { recipients, .. }
+            | Self::EscalateToCoordinator { recipients, .. }
+            | Self::DispatchAppFolioAlert { recipients, .. } => recipients,
+        }
+    }
+
+    /// Compliance topics an escalation concerns; empty for other actions.
+    pub fn topics(&self) -> &[String] {
+        match self {
+            Self::EscalateToCoordinator { topics, .. } => topics,
+            Self::RemindRole { .. } | Self::DispatchAppFolioAlert { .. } => &[],
+        }
+    }
+
+    /// Groups triggers for dedupe within a day: same vacancy, action, and role. The task
+    /// keys and topics already sent are tracked per group, so recipients changing hands
+    /// or tasks closing mid-day do not re-send anything, while newly listed work does.
+    fn dedupe_key(&self, vacancy_id: &str) -> String {
+        let role = match self {
+            Self::RemindRole { role, .. } => role.label(),
+            Self::EscalateToCoordinator { .. } => VacancyRole::ComplianceCoordinator.label(),
+            Self::DispatchAppFolioAlert { .. } => "",
+        };
+        format!("{}|{}|{}", vacancy_id, self.template(), role)
+    }
+
+    /// Copy of the trigger narrowed to the given task keys and topics.
+    fn narrowed(&self, task_keys: Vec<String>, topics: Vec<String>) -> Self {
+        match self {
+            Self::RemindRole {
+                role, recipients, ..
+            } => Self::RemindRole {
+                role: *role,
+                task_keys,
+                recipients: recipients.clone(),
+            },
+            Self::EscalateToCoordinator { recipients, .. } => Self::EscalateToCoordinator {
+                task_keys,
+                topics,
+                recipients: recipients.clone(),
+            },
+            Self::DispatchAppFolioAlert { recipients, .. } => Self::DispatchAppFolioAlert {
+                task_keys,
+                recipients: recipients.clone(),
+            },
+        }
+    }
+
+    pub fn to_alert(&self, vacancy_id: &str) -> VacancyAlert {
+        let mut details = BTreeMap::new();
+        details.insert("task_keys".to_string(), self.task_keys().join(","));
+        if !self.recipients().is_empty() {
+            details.insert("recipients".to_string(), self.recipients().join(","));
+        }
+        match self {
+            Self::RemindRole { role, .. } => {
+                details.insert("role".to_string(), role.label().to_string());
+            }
+            Self::EscalateToCoordinator { topics, .. } => {
+                details.insert("topics".to_string(), topics.join("; "));
+            }
+            Self::DispatchAppFolioAlert { .. } => {}
+        }
+
+        VacancyAlert {
+            template: self.template().to_string(),
+            vacancy_id: vacancy_id.to_string(),
+            details,
+        }
+    }
+}
+
+/// Builds the typed triggers behind a report summary's automation guidance.
+pub(crate) fn plan_triggers(
+    summary: &VacancyReportSummary,
+    instance: &VacancyWorkflowInstance,
+) -> Vec<AutomationTrigger> {
+    let assignees: HashMap<&str, &str> = instance
+        .tasks()
+        .iter()
+        .filter_map(|task| {
+            task.assignee
+                .as_deref()
+                .map(|assignee| (task.template.key.as_str(), assignee))
+        })
+        .collect();
+    let recipients_for = |keys: &[String]| -> Vec<String> {
+        keys.iter()
+            .filter_map(|key| assignees.get(key.as_str()))
+            .map(|assignee| assignee.to_string())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    };
+
+    let mut triggers = Vec::new();
+    for role in VacancyRole::ordered() {
+        let task_keys: Vec<String> = instance
+            .tasks()
+            .iter()
+            .filter(|task| {
+                task.template.primary_role == role
+                    && task.status != TaskStatus::Completed
+                    && instance.pending_prerequisites(task).is_empty()
+            })
+            .map(|task| task.template.key.clone())
+            .collect();
+        if task_keys.is_empty() {
+            continue;
+        }
+        triggers.push(AutomationTrigger::RemindRole {
+            role,
+            recipients: recipients_for(&task_keys),
+            task_keys,
+        });
+    }
+
+    let escalated: Vec<_> = summary
+        .compliance_alerts
+        .iter()
+        .filter(|alert| alert.severity >= ComplianceSeverity::Warning)
+        .collect();
+    if !escalated.is_empty() {
+        let mut task_keys: Vec<String> = Vec::new();
+        let mut topics: Vec<String> = Vec::new();
+        for alert in escalated {
+            if !task_keys.contains(&alert.task_key) {
+                task_keys.push(alert.task_key.clone());
+            }
+            if !topics.contains(&alert.topic) {
+                topics.push(alert.topic.clone());
+            }
+        }
+        let coordinator_tasks: Vec<String> = instance
+            .tasks()
+            .iter()
+            .filter(|task| {
+                task.template.primary_role == VacancyRole::ComplianceCoordinator
+                    && task.status != TaskStatus::Completed
+            })
+            .map(|task| task.template.key.clone())
+            .collect();
+        triggers.push(AutomationTrigger::EscalateToCoordinator {
+            recipients: recipients_for(&coordinator_tasks),
+            task_keys,
+            topics,
+        });
+    }
+
+    if !summary.overdue_tasks.is_empty() {
+        let task_keys: Vec<String> = summary
+            .overdue_tasks
+            .iter()
+            .map(|task| task.key.clone())
+            .collect();
+        triggers.push(AutomationTrigger::DispatchAppFolioAlert {
+            recipients: recipients_for(&task_keys),
+            task_keys,
+        });
+    }
+
+    triggers
+}
+
+/// Triggers published and suppressed by one [`AutomationDispatcher::dispatch`] call.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DispatchOutcome {
+    /// Triggers as published, narrowed to the task keys and topics not yet sent that day.
+    pub sent: Vec<AutomationTrigger>,
+    /// Triggers whose task keys and topics were all sent for the vacancy earlier that day.
+    pub suppressed: Vec<AutomationTrigger>,
+}
+
+/// Task keys and topics already published for one dedupe group on one day.
+#[derive(Debug, Default)]
+struct SentItems {
+    task_keys: HashSet<String>,
+    topics: HashSet<String>,
+}
+
+/// Publishes automation triggers as [`VacancyAlert`]s through an [`AlertPublisher`],
+/// sending each task key and topic at most once per vacancy, action, and role per day.
+pub struct AutomationDispatcher {
+    alerts: Arc<dyn AlertPublisher>,
+    sent: Mutex<HashMap<NaiveDate, HashMap<String, SentItems>>>,
+}
+
+impl AutomationDispatcher {
+    pub fn new(alerts: Arc<dyn AlertPublisher>) -> Self {
+        Self {
+            alerts,
+            sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Publishes `triggers` for `vacancy_id` as of `today`. A trigger that lists task keys
+    /// or topics not yet sent that day goes out narrowed to just those; one that lists
+    /// nothing new is suppressed. Stops at the first publisher error; triggers sent
+    /// before it stay recorded so a retry does not repeat them.
+    pub fn dispatch(
+        &self,
+        vacancy_id: &str,
+        triggers: &[AutomationTrigger],
+        today: NaiveDate,
+    ) -> Result<DispatchOutcome, AlertError> {
+        let mut sent_log = self.sent.lock().expect("dispatch log mutex poisoned");
+        sent_log.retain(|date, _| *date >= today);
+        let sent_today = sent_log.entry(today).or_default();
+
+        let mut outcome = DispatchOutcome::default();
+        for trigger in triggers {
+            let already_sent = sent_today
+                .entry(trigger.dedupe_key(vacancy_id))
+                .or_default();
+            let task_keys: Vec<String> = trigger
+                .task_keys()
+                .iter()
+                .filter(|key| !already_sent.task_keys.contains(*key))
+                .cloned()
+                .collect();
+            let topics: Vec<String> = trigger
+                .topics()
+                .iter()
+                .filter(|topic| !already_sent.topics.contains(*topic))
+                .cloned()
+                .collect();
+            if task_keys.is_empty() && topics.is_empty() {
+                outcome.suppressed.push(trigger.clone());
+                continue;
+            }
+
+            let fresh = trigger.narrowed(task_keys, topics);
+            self.alerts.publish_vacancy(fresh.to_alert(vacancy_id))?;
+            already_sent
+                .task_keys
+                .extend(fresh.task_keys().iter().cloned());
+            already_sent.topics.extend(fresh.topics().iter().cloned());
+            outcome.sent.push(fresh);
+        }
+        Ok(outcome)
+    }
+}
+
+impl std::fmt::Debug for AutomationDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AutomationDispatcher")
+            .finish_non_exhaustive()
+    }
+}
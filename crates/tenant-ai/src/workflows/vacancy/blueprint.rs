@@ -1,17 +1,183 @@
-use super::domain::{ComplianceNote, DueDateRule, TaskTemplate, VacancyRole, VacancyStage};
+use super::dependencies::dependency_order;
+use super::domain::{
+    ComplianceNote, Deliverable, DueDateRule, TaskTemplate, VacancyRole, VacancyStage,
+};
+use super::registry::{BlueprintRegistry, LIHTC_BLUEPRINT};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Serialization formats accepted by the declarative blueprint loader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlueprintFormat {
+    Toml,
+    Yaml,
+}
+
+impl BlueprintFormat {
+    /// Infers the format from a file extension (`.toml`, `.yaml`, `.yml`).
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
 
 #[derive(Debug)]
+pub enum BlueprintError {
+    Io(std::io::Error),
+    UnsupportedFormat(PathBuf),
+    Toml(toml::de::Error),
+    Yaml(serde_yaml::Error),
+    DuplicateTaskKey(String),
+    UnknownStage {
+        task_key: String,
+        stage: String,
+    },
+    UnknownRole {
+        task_key: String,
+        role: String,
+    },
+    UnknownPrerequisite {
+        task_key: String,
+        prerequisite: String,
+    },
+    DependencyCycle(Vec<String>),
+    UnknownBase(String),
+    UnknownRemovedTask(String),
+}
+
+impl fmt::Display for BlueprintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlueprintError::Io(err) => write!(f, "failed to read blueprint: {}", err),
+            BlueprintError::UnsupportedFormat(path) => write!(
+                f,
+                "unsupported blueprint format for {} (expected .toml, .yaml, or .yml)",
+                path.display()
+            ),
+            BlueprintError::Toml(err) => write!(f, "invalid blueprint TOML: {}", err),
+            BlueprintError::Yaml(err) => write!(f, "invalid blueprint YAML: {}", err),
+            BlueprintError::DuplicateTaskKey(key) => {
+                write!(f, "task key {} is declared more than once", key)
+            }
+            BlueprintError::UnknownStage { task_key, stage } => {
+                write!(f, "task {} references unknown stage {}", task_key, stage)
+            }
+            BlueprintError::UnknownRole { task_key, role } => {
+                write!(f, "task {} references unknown role {}", task_key, role)
+            }
+            BlueprintError::UnknownPrerequisite {
+                task_key,
+                prerequisite,
+            } => write!(
+                f,
+                "task {} requires unknown prerequisite {}",
+                task_key, prerequisite
+            ),
+            BlueprintError::DependencyCycle(keys) => {
+                write!(f, "task prerequisites form a cycle: {}", keys.join(" -> "))
+            }
+            BlueprintError::UnknownBase(name) => {
+                write!(f, "blueprint extends unknown base {}", name)
+            }
+            BlueprintError::UnknownRemovedTask(key) => {
+                write!(f, "cannot remove task {}: not present in the base", key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BlueprintError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BlueprintError::Io(err) => Some(err),
+            BlueprintError::Toml(err) => Some(err),
+            BlueprintError::Yaml(err) => Some(err),
+            BlueprintError::UnsupportedFormat(_)
+            | BlueprintError::DuplicateTaskKey(_)
+            | BlueprintError::UnknownStage { .. }
+            | BlueprintError::UnknownRole { .. }
+            | BlueprintError::UnknownPrerequisite { .. }
+            | BlueprintError::DependencyCycle(_)
+            | BlueprintError::UnknownBase(_)
+            | BlueprintError::UnknownRemovedTask(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for BlueprintError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for BlueprintError {
+    fn from(err: toml::de::Error) -> Self {
+        Self::Toml(err)
+    }
+}
+
+impl From<serde_yaml::Error> for BlueprintError {
+    fn from(err: serde_yaml::Error) -> Self {
+        Self::Yaml(err)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct VacancyWorkflowBlueprint {
     tasks: Vec<TaskTemplate>,
+    order: Vec<usize>,
 }
 
 impl VacancyWorkflowBlueprint {
+    /// The bundled LIHTC checklist, which the other bundled programs extend.
     pub fn standard() -> Self {
-        Self {
-            tasks: standard_task_templates(),
+        Self::from_reader(LIHTC_BLUEPRINT.as_bytes(), BlueprintFormat::Toml)
+            .expect("bundled LIHTC blueprint is valid")
+    }
+
+    /// Loads a blueprint file, choosing the parser from the file extension.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, BlueprintError> {
+        let path = path.as_ref();
+        let format = BlueprintFormat::from_path(path)
+            .ok_or_else(|| BlueprintError::UnsupportedFormat(path.to_path_buf()))?;
+        let file = std::fs::File::open(path)?;
+        Self::from_reader(file, format)
+    }
+
+    /// Parses a blueprint document. A document that `extends` another blueprint is
+    /// resolved against [`BlueprintRegistry::shared_builtin`].
+    pub fn from_reader<R: Read>(
+        reader: R,
+        format: BlueprintFormat,
+    ) -> Result<Self, BlueprintError> {
+        let document = BlueprintDocument::parse(reader, format)?;
+        match document.extends.as_deref() {
+            Some(_) => document.resolve(BlueprintRegistry::shared_builtin()),
+            None => document.resolve(&BlueprintRegistry::new()),
         }
     }
 
+    /// Builds a blueprint from templates, rejecting duplicate task keys, unknown
+    /// prerequisites, and prerequisite cycles.
+    pub fn from_templates(tasks: Vec<TaskTemplate>) -> Result<Self, BlueprintError> {
+        let mut seen = HashSet::with_capacity(tasks.len());
+        for task in &tasks {
+            if !seen.insert(task.key.as_str()) {
+                return Err(BlueprintError::DuplicateTaskKey(task.key.clone()));
+            }
+        }
+        let order = dependency_order(&tasks)?;
+
+        Ok(Self { tasks, order })
+    }
+
     pub fn tasks_for_stage(&self, stage: VacancyStage) -> Vec<&TaskTemplate> {
         self.tasks
             .iter()
@@ -22,169 +188,135 @@ impl VacancyWorkflowBlueprint {
     pub fn task_templates(&self) -> &[TaskTemplate] {
         &self.tasks
     }
+
+    /// Indices into [`Self::task_templates`] with every prerequisite ahead of its dependents.
+    pub(crate) fn task_order(&self) -> &[usize] {
+        &self.order
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BlueprintDocument {
+    #[serde(default)]
+    extends: Option<String>,
+    #[serde(default)]
+    remove: Vec<String>,
+    #[serde(default)]
+    tasks: Vec<TaskDefinition>,
+}
+
+impl BlueprintDocument {
+    pub(crate) fn parse<R: Read>(
+        mut reader: R,
+        format: BlueprintFormat,
+    ) -> Result<Self, BlueprintError> {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+
+        Ok(match format {
+            BlueprintFormat::Toml => toml::from_str(&contents)?,
+            BlueprintFormat::Yaml => serde_yaml::from_str(&contents)?,
+        })
+    }
+
+    /// Builds the blueprint, starting from the `extends` base found in `registry`.
+    ///
+    /// Removed tasks are also dropped from every remaining task's prerequisites. A task
+    /// whose key exists in the base replaces it; any other task is appended.
+    pub(crate) fn resolve(
+        self,
+        registry: &BlueprintRegistry,
+    ) -> Result<VacancyWorkflowBlueprint, BlueprintError> {
+        let mut tasks = match self.extends.as_deref() {
+            Some(base) => registry
+                .get(base)
+                .ok_or_else(|| BlueprintError::UnknownBase(base.to_string()))?
+                .task_templates()
+                .to_vec(),
+            None => Vec::new(),
+        };
+
+        for removed in &self.remove {
+            let before = tasks.len();
+            tasks.retain(|task| task.key != *removed);
+            if tasks.len() == before {
+                return Err(BlueprintError::UnknownRemovedTask(removed.clone()));
+            }
+            for task in &mut tasks {
+                task.prerequisites.retain(|key| key != removed);
+            }
+        }
+
+        let mut declared = HashSet::with_capacity(self.tasks.len());
+        for definition in self.tasks {
+            let template = definition.into_template()?;
+            if !declared.insert(template.key.clone()) {
+                return Err(BlueprintError::DuplicateTaskKey(template.key));
+            }
+            match tasks.iter_mut().find(|task| task.key == template.key) {
+                Some(existing) => *existing = template,
+                None => tasks.push(template),
+            }
+        }
+
+        VacancyWorkflowBlueprint::from_templates(tasks)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TaskDefinition {
+    key: String,
+    name: String,
+    stage: String,
+    role: String,
+    #[serde(with = "serde_yaml::with::singleton_map")]
+    due: DueDateRule,
+    #[serde(default = "default_duration_days")]
+    duration_days: u32,
+    #[serde(default = "default_task_weight")]
+    weight: u32,
+    #[serde(default)]
+    deliverables: Vec<Deliverable>,
+    #[serde(default)]
+    compliance: Vec<ComplianceNote>,
+    #[serde(default)]
+    prerequisites: Vec<String>,
+}
+
+impl TaskDefinition {
+    fn into_template(self) -> Result<TaskTemplate, BlueprintError> {
+        let stage =
+            VacancyStage::from_key(&self.stage).ok_or_else(|| BlueprintError::UnknownStage {
+                task_key: self.key.clone(),
+                stage: self.stage.clone(),
+            })?;
+        let primary_role =
+            VacancyRole::from_key(&self.role).ok_or_else(|| BlueprintError::UnknownRole {
+                task_key: self.key.clone(),
+                role: self.role.clone(),
+            })?;
+
+        Ok(TaskTemplate {
+            key: self.key,
+            name: self.name,
+            stage,
+            primary_role,
+            due: self.due,
+            duration_days: self.duration_days,
+            weight: self.weight,
+            deliverables: self.deliverables,
+            compliance: self.compliance,
+            prerequisites: self.prerequisites,
+        })
+    }
+}
+
+fn default_duration_days() -> u32 {
+    1
 }
 
-fn standard_task_templates() -> Vec<TaskTemplate> {
-    vec![
-        TaskTemplate {
-            key: "marketing_publish_listing",
-            name: "Create and Publish Listing",
-            stage: VacancyStage::MarketingAndAdvertising,
-            primary_role: VacancyRole::LeasingAgent,
-            due: DueDateRule::DaysFromVacancy(0),
-            deliverables: vec![
-                "Draft a fresh listing that highlights unit features, affordability programs, and rent ready date.",
-                "Upload current listing photos or virtual tour links before publishing.",
-                "Syndicate to Zillow, Apartments.com, social media, and capture marketing URLs for reporting.",
-            ],
-            compliance: vec![ComplianceNote {
-                topic: "Iowa Code § 562A.29 reasonable re-rental efforts",
-                detail: "Document every marketing channel touch to evidence reasonable efforts to re-rent (Iowa Code § 562A.29).",
-            }],
-        },
-        TaskTemplate {
-            key: "marketing_update_appfolio",
-            name: "Update Vacancy Status in AppFolio",
-            stage: VacancyStage::MarketingAndAdvertising,
-            primary_role: VacancyRole::LeasingAgent,
-            due: DueDateRule::DaysFromVacancy(0),
-            deliverables: vec![
-                "Switch the unit status from \"Turnover\" to \"Vacant\" in AppFolio immediately after make-ready sign-off.",
-                "Confirm listing syndication triggers fired for all partner channels.",
-            ],
-            compliance: vec![ComplianceNote {
-                topic: "System of record accuracy",
-                detail: "Accurate AppFolio statuses keep vacancy analytics, owner reporting, and marketing automation in sync.",
-            }],
-        },
-        TaskTemplate {
-            key: "screening_manage_inquiries",
-            name: "Manage Inquiries and Schedule Showings",
-            stage: VacancyStage::ScreeningAndApplication,
-            primary_role: VacancyRole::LeasingAgent,
-            due: DueDateRule::DaysFromVacancy(0),
-            deliverables: vec![
-                "Respond to every inquiry within one business day using standardized messaging to preserve Fair Housing parity.",
-                "Capture pre-screen answers covering move timeline, household composition, pets, and program eligibility.",
-                "Offer pre-defined showing blocks via scheduling links to minimize back-and-forth.",
-            ],
-            compliance: vec![ComplianceNote {
-                topic: "Fair Housing and Iowa Civil Rights Act parity",
-                detail: "Consistent response cadences prevent disparate treatment across protected classes and leave an audit trail.",
-            }],
-        },
-        TaskTemplate {
-            key: "screening_process_applications",
-            name: "Process Rental Applications",
-            stage: VacancyStage::ScreeningAndApplication,
-            primary_role: VacancyRole::LeasingAgent,
-            due: DueDateRule::DaysFromVacancy(2),
-            deliverables: vec![
-                "Review each application within 48 hours and request missing fields immediately.",
-                "Collect income, asset, and household documentation aligned with LIHTC and program requirements.",
-                "Complete credit, background, and landlord verifications before rendering a decision.",
-            ],
-            compliance: vec![
-                ComplianceNote {
-                    topic: "Documented screening criteria",
-                    detail: "Apply published screening criteria uniformly and retain documentation for adverse action defense.",
-                },
-                ComplianceNote {
-                    topic: "LIHTC source-of-income verification",
-                    detail: "Secure third-party income documentation to support Tenant Income Certification (TIC) files.",
-                },
-            ],
-        },
-        TaskTemplate {
-            key: "screening_notify_applicants",
-            name: "Notify Applicants of Status",
-            stage: VacancyStage::ScreeningAndApplication,
-            primary_role: VacancyRole::LeasingAgent,
-            due: DueDateRule::DaysFromVacancy(2),
-            deliverables: vec![
-                "Send approvals with next-step instructions and payment expectations.",
-                "Issue denials with compliant adverse action language and timestamp outcomes in the CRM.",
-            ],
-            compliance: vec![ComplianceNote {
-                topic: "Adverse action documentation",
-                detail: "Retain copies of denial notices and credit disclosures to satisfy Fair Credit Reporting Act obligations.",
-            }],
-        },
-        TaskTemplate {
-            key: "leasing_prepare_agreement",
-            name: "Prepare Lease Agreement",
-            stage: VacancyStage::LeaseSigningAndMoveIn,
-            primary_role: VacancyRole::LeasingAgent,
-            due: DueDateRule::DaysFromVacancy(5),
-            deliverables: vec![
-                "Merge approved terms into the LIHTC-compliant lease packet and distribute for e-signature.",
-                "Confirm all addenda (e.g., VAWA, house rules) are attached before sending.",
-            ],
-            compliance: vec![ComplianceNote {
-                topic: "Lease artifact completeness",
-                detail: "Incomplete lease packets jeopardize move-in readiness and downstream LIHTC audits.",
-            }],
-        },
-        TaskTemplate {
-            key: "leasing_collect_funds",
-            name: "Collect Move-In Funds",
-            stage: VacancyStage::LeaseSigningAndMoveIn,
-            primary_role: VacancyRole::PropertyManagerAccounting,
-            due: DueDateRule::DaysBeforeMoveIn(5),
-            deliverables: vec![
-                "Collect prorated rent, deposits, and fees; post receipts to the resident ledger.",
-                "Confirm deposit amounts stay within Iowa caps (≤ two months rent).",
-            ],
-            compliance: vec![ComplianceNote {
-                topic: "Security deposit limits",
-                detail: "Deposits exceeding state limits expose the portfolio to statutory penalties.",
-            }],
-        },
-        TaskTemplate {
-            key: "leasing_conduct_move_in_inspection",
-            name: "Conduct Move-In Inspection",
-            stage: VacancyStage::LeaseSigningAndMoveIn,
-            primary_role: VacancyRole::PropertyManager,
-            due: DueDateRule::OnMoveIn,
-            deliverables: vec![
-                "Complete digital inspection checklist with tenant present and capture photos of every room.",
-                "Upload signed inspection and media to AppFolio for permanent recordkeeping.",
-            ],
-            compliance: vec![ComplianceNote {
-                topic: "Move-in condition documentation",
-                detail: "Thorough inspections limit security deposit disputes and support future turn charges.",
-            }],
-        },
-        TaskTemplate {
-            key: "leasing_lihtc_certification",
-            name: "Complete LIHTC Initial Certification",
-            stage: VacancyStage::LeaseSigningAndMoveIn,
-            primary_role: VacancyRole::ComplianceCoordinator,
-            due: DueDateRule::DaysBeforeMoveIn(3),
-            deliverables: vec![
-                "Collect signed Tenant Income Certification (TIC) and applicable student status affidavits.",
-                "Verify income against current IFA limits and retain third-party documentation.",
-                "Issue VAWA notices and ensure household files are audit ready.",
-            ],
-            compliance: vec![ComplianceNote {
-                topic: "LIHTC eligibility lock-in",
-                detail: "Certification must be finalized at least three days before move-in to maintain LIHTC compliance.",
-            }],
-        },
-        TaskTemplate {
-            key: "handoff_start_new_resident_workflow",
-            name: "Handoff to New Resident Workflow",
-            stage: VacancyStage::Handoff,
-            primary_role: VacancyRole::PropertyManager,
-            due: DueDateRule::OnMoveIn,
-            deliverables: vec![
-                "Update the unit status from \"Vacant\" to \"Occupied\" in AppFolio once keys are released.",
-                "Trigger the New Resident onboarding workflow with welcome communications and follow-up tasks.",
-            ],
-            compliance: vec![ComplianceNote {
-                topic: "Operational handoff completeness",
-                detail: "Transitioning to onboarding ensures services, compliance tracking, and resident engagement continue seamlessly.",
-            }],
-        },
-    ]
+pub(crate) fn default_task_weight() -> u32 {
+    1
 }
@@ -0,0 +1,247 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Business-day calendar used to resolve business-day due dates and to decide when a
+/// task becomes overdue. Saturdays, Sundays, and configured holidays are non-working.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BusinessCalendar {
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl BusinessCalendar {
+    pub fn with_holidays<I>(holidays: I) -> Self
+    where
+        I: IntoIterator<Item = NaiveDate>,
+    {
+        Self {
+            holidays: holidays.into_iter().collect(),
+        }
+    }
+
+    /// Loads holidays from a `.csv` or `.ics` file, choosing the parser from the extension.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, CalendarError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("csv") => Self::from_csv_reader(std::fs::File::open(path)?),
+            Some("ics") | Some("ical") => Self::from_ical_reader(std::fs::File::open(path)?),
+            _ => Err(CalendarError::UnsupportedFormat(path.to_path_buf())),
+        }
+    }
+
+    /// Parses a CSV whose first column holds `YYYY-MM-DD` holiday dates. A header row
+    /// and any trailing columns (e.g. holiday names) are ignored.
+    pub fn from_csv_reader<R: Read>(reader: R) -> Result<Self, CalendarError> {
+        let mut csv_reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        let mut holidays = BTreeSet::new();
+        for (row, record) in csv_reader.records().enumerate() {
+            let record = record?;
+            let Some(value) = record.get(0).filter(|value| !value.is_empty()) else {
+                continue;
+            };
+            match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+                Ok(date) => {
+                    holidays.insert(date);
+                }
+                Err(_) if row == 0 => continue,
+                Err(_) => {
+                    return Err(CalendarError::InvalidDate {
+                        line: row + 1,
+                        value: value.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(Self { holidays })
+    }
+
+    /// Parses every `VEVENT` in an iCalendar feed as a closure from `DTSTART` up to its
+    /// exclusive `DTEND`, so multi-day events close each day they cover. Dates outside
+    /// events, such as `VTIMEZONE` transitions, are ignored. Folded content lines are
+    /// joined before parsing.
+    pub fn from_ical_reader<R: Read>(mut reader: R) -> Result<Self, CalendarError> {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+
+        let mut holidays = BTreeSet::new();
+        let mut event: Option<(Option<NaiveDate>, Option<NaiveDate>)> = None;
+        for (index, line) in unfold_ical_lines(&contents) {
+            let line = line.trim();
+            let (name, value) = line.split_once(':').unwrap_or((line, ""));
+            let property = name.split(';').next().unwrap_or_default();
+            match (property, event.as_mut()) {
+                ("BEGIN", None) if value == "VEVENT" => event = Some((None, None)),
+                ("END", Some((start, end))) if value == "VEVENT" => {
+                    if let Some(start) = *start {
+                        holidays.insert(start);
+                        if let Some(end) = *end {
+                            holidays.extend(start.iter_days().take_while(|date| *date < end));
+                        }
+                    }
+                    event = None;
+                }
+                ("DTSTART", Some((start, _))) => *start = Some(ical_date(value, index, false)?),
+                ("DTEND", Some((_, end))) => *end = Some(ical_date(value, index, true)?),
+                _ => {}
+            }
+        }
+
+        Ok(Self { holidays })
+    }
+
+    pub fn holidays(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.holidays.iter().copied()
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.holidays.contains(&date)
+    }
+
+    /// Returns `date` when it is a business day, otherwise the next business day.
+    pub fn roll_forward(&self, date: NaiveDate) -> NaiveDate {
+        let mut current = date;
+        while !self.is_business_day(current) {
+            current += Duration::days(1);
+        }
+        current
+    }
+
+    /// Counts `days` business days forward, starting from the first business day on or
+    /// after `date`.
+    pub fn add_business_days(&self, date: NaiveDate, days: u32) -> NaiveDate {
+        let mut current = self.roll_forward(date);
+        for _ in 0..days {
+            current = self.roll_forward(current + Duration::days(1));
+        }
+        current
+    }
+
+    /// Counts `days` business days backward from `date`.
+    pub fn subtract_business_days(&self, date: NaiveDate, days: u32) -> NaiveDate {
+        let mut current = date;
+        let mut remaining = days;
+        while remaining > 0 {
+            current -= Duration::days(1);
+            if self.is_business_day(current) {
+                remaining -= 1;
+            }
+        }
+        current
+    }
+
+    /// Business days in `[from, to)`, negative when `to` is before `from`.
+    pub fn business_days_between(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        let (start, end, sign) = if from <= to {
+            (from, to, 1)
+        } else {
+            (to, from, -1)
+        };
+        let count = start
+            .iter_days()
+            .take_while(|date| *date < end)
+            .filter(|date| self.is_business_day(*date))
+            .count() as i64;
+        sign * count
+    }
+
+    /// A task is overdue once the first business day on or after its due date has passed,
+    /// so work due on a weekend or holiday is not flagged until the next working day ends.
+    pub fn is_overdue(&self, due_date: NaiveDate, today: NaiveDate) -> bool {
+        self.roll_forward(due_date) < today
+    }
+}
+
+/// Joins RFC 5545 continuation lines (those starting with a space or tab) onto the
+/// preceding content line, keeping the index of the line each logical line starts on.
+fn unfold_ical_lines(contents: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some((_, previous))) => previous.push_str(continuation),
+            _ => lines.push((index, line.to_string())),
+        }
+    }
+    lines
+}
+
+/// Reads the date of an iCalendar `DATE` or `DATE-TIME` value. An end time past midnight
+/// still closes its own day, so `exclusive_end` moves it to the following day.
+fn ical_date(value: &str, index: usize, exclusive_end: bool) -> Result<NaiveDate, CalendarError> {
+    let invalid = || CalendarError::InvalidDate {
+        line: index + 1,
+        value: value.to_string(),
+    };
+    let date = value
+        .get(..8)
+        .and_then(|digits| NaiveDate::parse_from_str(digits, "%Y%m%d").ok())
+        .ok_or_else(invalid)?;
+    let past_midnight = value
+        .get(9..15)
+        .is_some_and(|time| time.bytes().any(|digit| digit != b'0'));
+    if exclusive_end && past_midnight {
+        date.succ_opt().ok_or_else(invalid)
+    } else {
+        Ok(date)
+    }
+}
+
+#[derive(Debug)]
+pub enum CalendarError {
+    Io(std::io::Error),
+    Csv(csv::Error),
+    UnsupportedFormat(PathBuf),
+    InvalidDate { line: usize, value: String },
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarError::Io(err) => write!(f, "failed to read holiday calendar: {}", err),
+            CalendarError::Csv(err) => write!(f, "invalid holiday CSV: {}", err),
+            CalendarError::UnsupportedFormat(path) => write!(
+                f,
+                "unsupported holiday calendar format for {} (expected .csv or .ics)",
+                path.display()
+            ),
+            CalendarError::InvalidDate { line, value } => {
+                write!(f, "invalid holiday date '{}' on line {}", value, line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CalendarError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CalendarError::Io(err) => Some(err),
+            CalendarError::Csv(err) => Some(err),
+            CalendarError::UnsupportedFormat(_) | CalendarError::InvalidDate { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CalendarError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<csv::Error> for CalendarError {
+    fn from(err: csv::Error) -> Self {
+        Self::Csv(err)
+    }
+}
@@ -0,0 +1,86 @@
+use super::blueprint::BlueprintError;
+use super::domain::TaskTemplate;
+use std::collections::HashMap;
+
+/// Validates prerequisite references and returns task indices in dependency order.
+///
+/// Predecessors always appear before the tasks that depend on them; ties keep the
+/// declaration order so reports stay stable for blueprint authors.
+pub(crate) fn dependency_order(tasks: &[TaskTemplate]) -> Result<Vec<usize>, BlueprintError> {
+    let index: HashMap<&str, usize> = tasks
+        .iter()
+        .enumerate()
+        .map(|(position, task)| (task.key.as_str(), position))
+        .collect();
+
+    let mut edges: Vec<Vec<usize>> = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        let mut predecessors = Vec::with_capacity(task.prerequisites.len());
+        for prerequisite in &task.prerequisites {
+            let position = index.get(prerequisite.as_str()).copied().ok_or_else(|| {
+                BlueprintError::UnknownPrerequisite {
+                    task_key: task.key.clone(),
+                    prerequisite: prerequisite.clone(),
+                }
+            })?;
+            predecessors.push(position);
+        }
+        edges.push(predecessors);
+    }
+
+    let mut state = vec![VisitState::Unvisited; tasks.len()];
+    let mut order = Vec::with_capacity(tasks.len());
+    let mut path = Vec::new();
+    for start in 0..tasks.len() {
+        visit(start, &edges, &mut state, &mut path, &mut order).map_err(|cycle| {
+            BlueprintError::DependencyCycle(
+                cycle
+                    .into_iter()
+                    .map(|position| tasks[position].key.clone())
+                    .collect(),
+            )
+        })?;
+    }
+
+    Ok(order)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+fn visit(
+    node: usize,
+    edges: &[Vec<usize>],
+    state: &mut [VisitState],
+    path: &mut Vec<usize>,
+    order: &mut Vec<usize>,
+) -> Result<(), Vec<usize>> {
+    match state[node] {
+        VisitState::Done => return Ok(()),
+        VisitState::InProgress => {
+            let start = path
+                .iter()
+                .position(|&entry| entry == node)
+                .unwrap_or_default();
+            let mut cycle = path[start..].to_vec();
+            cycle.push(node);
+            return Err(cycle);
+        }
+        VisitState::Unvisited => {}
+    }
+
+    state[node] = VisitState::InProgress;
+    path.push(node);
+    for &predecessor in &edges[node] {
+        visit(predecessor, edges, state, path, order)?;
+    }
+    path.pop();
+    state[node] = VisitState::Done;
+    order.push(node);
+
+    Ok(())
+}
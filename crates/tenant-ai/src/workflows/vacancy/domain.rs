@@ -1,4 +1,6 @@
-use chrono::{Duration, NaiveDate};
+use super::applications::DocumentDescriptor;
+use super::calendar::BusinessCalendar;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -21,6 +23,21 @@ impl VacancyStage {
         ]
     }
 
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::MarketingAndAdvertising => "marketing_and_advertising",
+            Self::ScreeningAndApplication => "screening_and_application",
+            Self::LeaseSigningAndMoveIn => "lease_signing_and_move_in",
+            Self::Handoff => "handoff",
+        }
+    }
+
+    pub fn from_key(value: &str) -> Option<Self> {
+        Self::ordered()
+            .into_iter()
+            .find(|stage| stage.key() == value.trim())
+    }
+
     pub const fn label(self) -> &'static str {
         match self {
             Self::MarketingAndAdvertising => "Marketing & Advertising",
@@ -50,6 +67,21 @@ impl VacancyRole {
         ]
     }
 
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::LeasingAgent => "leasing_agent",
+            Self::ComplianceCoordinator => "compliance_coordinator",
+            Self::PropertyManager => "property_manager",
+            Self::PropertyManagerAccounting => "property_manager_accounting",
+        }
+    }
+
+    pub fn from_key(value: &str) -> Option<Self> {
+        Self::ordered()
+            .into_iter()
+            .find(|role| role.key() == value.trim())
+    }
+
     pub const fn label(self) -> &'static str {
         match self {
             Self::LeasingAgent => "Leasing Agent",
@@ -80,9 +112,10 @@ impl TaskStatus {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ComplianceSeverity {
+    Info,
     Warning,
     Critical,
 }
@@ -90,55 +123,334 @@ pub enum ComplianceSeverity {
 impl ComplianceSeverity {
     pub const fn label(self) -> &'static str {
         match self {
+            Self::Info => "Info",
             Self::Warning => "Warning",
             Self::Critical => "Critical",
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Decides how loudly an open task's compliance note is surfaced in the report.
+///
+/// Notes are Info until the task is within `warning_window_days` of its due date,
+/// Warning inside the window, and Critical once the task is overdue or the note is
+/// marked statutory. Evidence on file drops a Warning back to Info.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeverityPolicy {
+    pub warning_window_days: u32,
+}
+
+impl SeverityPolicy {
+    pub const DEFAULT_WARNING_WINDOW_DAYS: u32 = 3;
+
+    /// Severity for `note` on an open task due in `days_until_due` days (negative once
+    /// past due); `overdue` follows the business calendar.
+    pub fn classify(
+        &self,
+        note: &ComplianceNote,
+        days_until_due: i64,
+        overdue: bool,
+        has_evidence: bool,
+    ) -> ComplianceSeverity {
+        if overdue || note.statutory {
+            return ComplianceSeverity::Critical;
+        }
+        let window = note.warning_window_days.unwrap_or(self.warning_window_days);
+        if has_evidence || days_until_due > i64::from(window) {
+            ComplianceSeverity::Info
+        } else {
+            ComplianceSeverity::Warning
+        }
+    }
+}
+
+impl Default for SeverityPolicy {
+    fn default() -> Self {
+        Self {
+            warning_window_days: Self::DEFAULT_WARNING_WINDOW_DAYS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DueDateRule {
     DaysFromVacancy(i64),
     DaysBeforeMoveIn(u32),
+    BusinessDaysFromVacancy(u32),
+    BusinessDaysBeforeMoveIn(u32),
     OnMoveIn,
 }
 
 impl DueDateRule {
-    pub(crate) fn resolve(&self, vacancy_start: NaiveDate, target_move_in: NaiveDate) -> NaiveDate {
+    pub(crate) fn resolve(
+        &self,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+        calendar: &BusinessCalendar,
+    ) -> NaiveDate {
         match self {
             DueDateRule::DaysFromVacancy(offset) => vacancy_start + Duration::days(*offset),
             DueDateRule::DaysBeforeMoveIn(days) => target_move_in - Duration::days(*days as i64),
+            DueDateRule::BusinessDaysFromVacancy(days) => {
+                calendar.add_business_days(vacancy_start, *days)
+            }
+            DueDateRule::BusinessDaysBeforeMoveIn(days) => {
+                calendar.subtract_business_days(target_move_in, *days)
+            }
             DueDateRule::OnMoveIn => target_move_in,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ComplianceNote {
-    pub topic: &'static str,
-    pub detail: &'static str,
+    pub topic: String,
+    pub detail: String,
+    /// Completing the task without evidence attached to this note escalates the alert.
+    #[serde(default)]
+    pub evidence_required: bool,
+    /// Statutory notes stay Critical for as long as the task is open.
+    #[serde(default)]
+    pub statutory: bool,
+    /// Overrides [`SeverityPolicy::warning_window_days`] for this note.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning_window_days: Option<u32>,
+}
+
+/// Proof recorded against one of a task's compliance notes, matched by topic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceEvidence {
+    pub topic: String,
+    pub document: DocumentDescriptor,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub captured_at: DateTime<Utc>,
+}
+
+/// Checklist item a task is expected to produce. Blueprints may list a plain string,
+/// which is required, or a `{ name, required }` table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "DeliverableDefinition")]
+pub struct Deliverable {
+    pub name: String,
+    pub required: bool,
 }
 
-#[derive(Debug, Clone)]
+impl Deliverable {
+    pub fn required(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            required: true,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DeliverableDefinition {
+    Name(String),
+    Detailed {
+        name: String,
+        #[serde(default = "default_required")]
+        required: bool,
+    },
+}
+
+fn default_required() -> bool {
+    true
+}
+
+impl From<DeliverableDefinition> for Deliverable {
+    fn from(value: DeliverableDefinition) -> Self {
+        match value {
+            DeliverableDefinition::Name(name) => Self::required(name),
+            DeliverableDefinition::Detailed { name, required } => Self { name, required },
+        }
+    }
+}
+
+/// Per-instance completion state for one template deliverable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Subtask {
+    pub name: String,
+    pub required: bool,
+    pub completed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_on: Option<NaiveDate>,
+    /// Checked off only because the whole task was completed; reopening the task unchecks it.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) checked_by_completion: bool,
+}
+
+impl From<&Deliverable> for Subtask {
+    fn from(deliverable: &Deliverable) -> Self {
+        Self {
+            name: deliverable.name.clone(),
+            required: deliverable.required,
+            completed: false,
+            completed_on: None,
+            checked_by_completion: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TaskTemplate {
-    pub key: &'static str,
-    pub name: &'static str,
+    pub key: String,
+    pub name: String,
     pub stage: VacancyStage,
     pub primary_role: VacancyRole,
     pub due: DueDateRule,
-    pub deliverables: Vec<&'static str>,
+    /// Working days the task needs once it can start; drives slack and critical path.
+    pub duration_days: u32,
+    /// Relative importance for weighted readiness scoring; 1 is an ordinary task.
+    #[serde(default = "super::blueprint::default_task_weight")]
+    pub weight: u32,
+    pub deliverables: Vec<Deliverable>,
     pub compliance: Vec<ComplianceNote>,
+    /// Keys of tasks that must be completed before this task can start.
+    pub prerequisites: Vec<String>,
+}
+
+/// Requested status transition plus the audit context recorded alongside it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusChange {
+    pub status: TaskStatus,
+    pub completed_on: Option<NaiveDate>,
+    pub actor: Option<VacancyRole>,
+    pub note: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl StatusChange {
+    /// Builds an unattributed change stamped with the current time.
+    pub fn new(status: TaskStatus, completed_on: Option<NaiveDate>) -> Self {
+        Self {
+            status,
+            completed_on,
+            actor: None,
+            note: None,
+            recorded_at: Utc::now(),
+        }
+    }
+}
+
+/// Append-only audit entry describing one status transition on a task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskEvent {
+    pub recorded_at: DateTime<Utc>,
+    pub from_status: TaskStatus,
+    pub to_status: TaskStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_on: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<VacancyRole>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Due date that moved when the vacancy window was rescheduled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DueDateShift {
+    pub task_key: String,
+    pub previous_due_date: NaiveDate,
+    pub due_date: NaiveDate,
+}
+
+/// Record of one reschedule: the old and new vacancy window and every task it re-dated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleChange {
+    pub recorded_at: DateTime<Utc>,
+    pub previous_vacancy_start: NaiveDate,
+    pub vacancy_start: NaiveDate,
+    pub previous_target_move_in: NaiveDate,
+    pub target_move_in: NaiveDate,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shifted_tasks: Vec<DueDateShift>,
+}
+
+impl ScheduleChange {
+    /// One-line description, e.g. "Move-in moved from 2025-10-08 to 2025-10-15; 4 task(s) re-dated".
+    pub fn summary(&self) -> String {
+        let moved = if self.previous_target_move_in == self.target_move_in {
+            format!(
+                "Vacancy start moved from {} to {}",
+                self.previous_vacancy_start, self.vacancy_start
+            )
+        } else {
+            format!(
+                "Move-in moved from {} to {}",
+                self.previous_target_move_in, self.target_move_in
+            )
+        };
+        format!("{}; {} task(s) re-dated", moved, self.shifted_tasks.len())
+    }
 }
 
 #[derive(Debug)]
 pub enum VacancyError {
     TaskNotFound(String),
+    PrerequisitesIncomplete {
+        task_key: String,
+        pending: Vec<String>,
+    },
+    UnknownBlueprint(String),
+    SubtaskNotFound {
+        task_key: String,
+        index: usize,
+    },
+    UnknownComplianceNote {
+        task_key: String,
+        topic: String,
+    },
+    /// Target move-in falls before the vacancy start.
+    InvalidSchedule {
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+    },
+    /// Property code or unit id is empty or contains the `:` separator.
+    InvalidVacancyId {
+        property_code: String,
+        unit_id: String,
+    },
 }
 
 impl fmt::Display for VacancyError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             VacancyError::TaskNotFound(key) => write!(f, "task with key {} not found", key),
+            VacancyError::PrerequisitesIncomplete { task_key, pending } => write!(
+                f,
+                "task {} is waiting on incomplete prerequisites: {}",
+                task_key,
+                pending.join(", ")
+            ),
+            VacancyError::UnknownBlueprint(name) => write!(f, "unknown vacancy blueprint {}", name),
+            VacancyError::SubtaskNotFound { task_key, index } => {
+                write!(f, "task {} has no subtask at position {}", task_key, index)
+            }
+            VacancyError::UnknownComplianceNote { task_key, topic } => write!(
+                f,
+                "task {} has no compliance note with topic {}",
+                task_key, topic
+            ),
+            VacancyError::InvalidSchedule {
+                vacancy_start,
+                target_move_in,
+            } => write!(
+                f,
+                "target move-in {} is before vacancy start {}",
+                target_move_in, vacancy_start
+            ),
+            VacancyError::InvalidVacancyId {
+                property_code,
+                unit_id,
+            } => write!(
+                f,
+                "property code '{}' and unit id '{}' must be non-empty and free of ':'",
+                property_code, unit_id
+            ),
         }
     }
 }
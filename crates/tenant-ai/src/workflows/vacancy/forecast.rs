@@ -0,0 +1,246 @@
+use super::domain::{TaskStatus, VacancyStage};
+use super::instance::{TaskInstance, VacancyWorkflowInstance};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Observed durations per task key in business days, counted the way the schedule counts
+/// [`super::domain::TaskTemplate::duration_days`], e.g. from past Apollo exports.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DurationHistory {
+    durations: HashMap<String, Vec<u32>>,
+}
+
+impl DurationHistory {
+    /// Tasks with fewer observations than this are sampled around their template duration.
+    pub const MIN_SAMPLES: usize = 3;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, task_key: &str, days: u32) {
+        self.durations
+            .entry(task_key.to_string())
+            .or_default()
+            .push(days);
+    }
+
+    pub fn merge(&mut self, other: DurationHistory) {
+        for (task_key, days) in other.durations {
+            self.durations.entry(task_key).or_default().extend(days);
+        }
+    }
+
+    pub fn samples(&self, task_key: &str) -> &[u32] {
+        self.durations
+            .get(task_key)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.durations.values().all(Vec::is_empty)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForecastOptions {
+    pub runs: u32,
+    /// Seed for the simulation; the same seed and inputs give the same forecast.
+    pub seed: u64,
+}
+
+impl ForecastOptions {
+    pub const DEFAULT_RUNS: u32 = 1_000;
+    pub const DEFAULT_SEED: u64 = 0x5eed_1dea;
+}
+
+impl Default for ForecastOptions {
+    fn default() -> Self {
+        Self {
+            runs: Self::DEFAULT_RUNS,
+            seed: Self::DEFAULT_SEED,
+        }
+    }
+}
+
+/// Simulated completion dates for one stage.
+#[derive(Debug, Clone, Serialize)]
+pub struct StageForecast {
+    pub stage: VacancyStage,
+    pub stage_label: &'static str,
+    pub p50: NaiveDate,
+    pub p90: NaiveDate,
+}
+
+/// Monte Carlo forecast of when the remaining work finishes. Durations are business days
+/// on the instance calendar, so weekends and holidays never count as progress.
+#[derive(Debug, Clone, Serialize)]
+pub struct VacancyForecast {
+    pub runs: u32,
+    pub target_move_in: NaiveDate,
+    /// Date by which every task is complete in half of the simulated runs.
+    pub move_in_ready_p50: NaiveDate,
+    pub move_in_ready_p90: NaiveDate,
+    /// Share of runs that finish every task on or before the target move-in.
+    pub on_time_probability: f32,
+    pub stages: Vec<StageForecast>,
+    /// Open tasks whose durations were drawn from history rather than the template.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history_backed_tasks: Vec<String>,
+}
+
+pub(crate) fn simulate(
+    instance: &VacancyWorkflowInstance,
+    today: NaiveDate,
+    history: &DurationHistory,
+    options: &ForecastOptions,
+) -> VacancyForecast {
+    let tasks: Vec<&TaskInstance> = instance.tasks_in_dependency_order().collect();
+    let position: HashMap<&str, usize> = tasks
+        .iter()
+        .enumerate()
+        .map(|(index, task)| (task.template.key.as_str(), index))
+        .collect();
+    let predecessors: Vec<Vec<usize>> = tasks
+        .iter()
+        .map(|task| {
+            task.template
+                .prerequisites
+                .iter()
+                .filter_map(|key| position.get(key.as_str()).copied())
+                .collect()
+        })
+        .collect();
+
+    let calendar = instance.calendar();
+    let start_floor = today.max(instance.vacancy_start());
+    let stages: Vec<VacancyStage> = VacancyStage::ordered()
+        .into_iter()
+        .filter(|stage| tasks.iter().any(|task| task.template.stage == *stage))
+        .collect();
+    let runs = options.runs.max(1);
+    let mut rng = SplitMix64::new(options.seed);
+    let mut ready_offsets = Vec::with_capacity(runs as usize);
+    let mut stage_offsets: Vec<Vec<f64>> = vec![Vec::with_capacity(runs as usize); stages.len()];
+    let mut finish = vec![0.0_f64; tasks.len()];
+
+    for _ in 0..runs {
+        for (index, task) in tasks.iter().enumerate() {
+            if task.status == TaskStatus::Completed {
+                let completed_on = task.completed_on.unwrap_or(start_floor);
+                finish[index] = calendar.business_days_between(start_floor, completed_on) as f64;
+                continue;
+            }
+            let start = predecessors[index]
+                .iter()
+                .map(|&predecessor| finish[predecessor])
+                .fold(0.0, f64::max);
+            let remaining = 1.0 - f64::from(task.progress());
+            finish[index] = start + sample_duration(task, history, &mut rng) * remaining;
+        }
+
+        ready_offsets.push(finish.iter().copied().fold(0.0, f64::max));
+        for (slot, stage) in stages.iter().enumerate() {
+            let stage_finish = tasks
+                .iter()
+                .zip(&finish)
+                .filter(|(task, _)| task.template.stage == *stage)
+                .map(|(_, offset)| *offset)
+                .fold(f64::MIN, f64::max);
+            stage_offsets[slot].push(stage_finish);
+        }
+    }
+
+    let to_date = |offset: f64| match offset.ceil() as i64 {
+        0 => start_floor,
+        days if days > 0 => calendar.add_business_days(start_floor, days as u32),
+        days => calendar.subtract_business_days(start_floor, days.unsigned_abs() as u32),
+    };
+    let target_offset =
+        calendar.business_days_between(start_floor, instance.target_move_in()) as f64;
+    let on_time = ready_offsets
+        .iter()
+        .filter(|offset| **offset <= target_offset)
+        .count();
+
+    let history_backed_tasks = tasks
+        .iter()
+        .filter(|task| task.status != TaskStatus::Completed)
+        .filter(|task| history.samples(&task.template.key).len() >= DurationHistory::MIN_SAMPLES)
+        .map(|task| task.template.key.clone())
+        .collect();
+
+    VacancyForecast {
+        runs,
+        target_move_in: instance.target_move_in(),
+        move_in_ready_p50: to_date(percentile(&mut ready_offsets, 0.5)),
+        move_in_ready_p90: to_date(percentile(&mut ready_offsets, 0.9)),
+        on_time_probability: on_time as f32 / runs as f32,
+        stages: stages
+            .into_iter()
+            .zip(stage_offsets.iter_mut())
+            .map(|(stage, offsets)| StageForecast {
+                stage,
+                stage_label: stage.label(),
+                p50: to_date(percentile(offsets, 0.5)),
+                p90: to_date(percentile(offsets, 0.9)),
+            })
+            .collect(),
+        history_backed_tasks,
+    }
+}
+
+/// Draws a duration in business days: uniformly from history when there is enough of it, otherwise
+/// from a triangular distribution skewed toward overruns of the template duration.
+fn sample_duration(task: &TaskInstance, history: &DurationHistory, rng: &mut SplitMix64) -> f64 {
+    let observed = history.samples(&task.template.key);
+    if observed.len() >= DurationHistory::MIN_SAMPLES {
+        let pick = (rng.next_f64() * observed.len() as f64) as usize;
+        return f64::from(observed[pick.min(observed.len() - 1)]);
+    }
+
+    let planned = f64::from(task.template.duration_days);
+    triangular(rng.next_f64(), planned * 0.75, planned, planned * 1.75)
+}
+
+fn triangular(unit: f64, low: f64, mode: f64, high: f64) -> f64 {
+    if high <= low {
+        return mode;
+    }
+    let split = (mode - low) / (high - low);
+    if unit < split {
+        low + (unit * (high - low) * (mode - low)).sqrt()
+    } else {
+        high - ((1.0 - unit) * (high - low) * (high - mode)).sqrt()
+    }
+}
+
+fn percentile(values: &mut [f64], quantile: f64) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let rank = ((values.len() as f64) * quantile).ceil() as usize;
+    values[rank.clamp(1, values.len()) - 1]
+}
+
+/// Small deterministic generator so forecasts are reproducible without an RNG dependency.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
@@ -1,16 +1,24 @@
+use super::applications::VacancyListingSnapshot;
+use super::automation::{plan_triggers, AutomationTrigger};
 use super::blueprint::VacancyWorkflowBlueprint;
+use super::calendar::BusinessCalendar;
 use super::domain::{
-    ComplianceNote, ComplianceSeverity, TaskStatus, TaskTemplate, VacancyError, VacancyRole,
-    VacancyStage,
+    ComplianceEvidence, ComplianceNote, ComplianceSeverity, DueDateShift, ScheduleChange,
+    SeverityPolicy, StatusChange, Subtask, TaskEvent, TaskStatus, TaskTemplate, VacancyError,
+    VacancyRole, VacancyStage,
 };
-use super::report::{ComplianceAlert, TaskSnapshot, VacancyReport};
-use chrono::NaiveDate;
-use serde::Serialize;
+use super::report::{
+    ComplianceAlert, ReadinessSnapshot, ReadinessTrend, TaskSnapshot, VacancyReport,
+};
+use super::schedule::{self, TaskScheduleView};
+use super::staffing::{staffing_report, StaffRoster, StaffingReport};
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct TaskDetailView {
-    pub key: &'static str,
-    pub name: &'static str,
+    pub key: String,
+    pub name: String,
     pub stage: VacancyStage,
     pub stage_label: &'static str,
     pub role: VacancyRole,
@@ -19,28 +27,171 @@ pub struct TaskDetailView {
     pub status: TaskStatus,
     pub status_label: &'static str,
     pub completed_on: Option<NaiveDate>,
-    pub deliverables: Vec<&'static str>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// Deliverable labels in template order, kept for clients that predate `subtasks`.
+    pub deliverables: Vec<String>,
+    pub subtasks: Vec<Subtask>,
     pub compliance: Vec<ComplianceNote>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evidence: Vec<ComplianceEvidence>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prerequisites: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub waiting_on: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<TaskEvent>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "StoredWorkflowInstance")]
 pub struct VacancyWorkflowInstance {
+    vacancy_start: NaiveDate,
+    target_move_in: NaiveDate,
+    calendar: BusinessCalendar,
+    tasks: Vec<TaskInstance>,
+    order: Vec<usize>,
+    #[serde(default)]
+    schedule_changes: Vec<ScheduleChange>,
+    #[serde(default)]
+    severity_policy: SeverityPolicy,
+    /// At most one readiness snapshot per day, oldest first.
+    #[serde(default)]
+    snapshots: Vec<ReadinessSnapshot>,
+    /// Monthly rent used to price the vacancy; unknown until a listing or caller sets it.
+    #[serde(default)]
+    monthly_rent: Option<u32>,
+}
+
+/// Serialized instance as read from storage, before `order` is checked against `tasks`.
+#[derive(Deserialize)]
+struct StoredWorkflowInstance {
+    vacancy_start: NaiveDate,
+    target_move_in: NaiveDate,
+    calendar: BusinessCalendar,
     tasks: Vec<TaskInstance>,
+    order: Vec<usize>,
+    #[serde(default)]
+    schedule_changes: Vec<ScheduleChange>,
+    #[serde(default)]
+    severity_policy: SeverityPolicy,
+    #[serde(default)]
+    snapshots: Vec<ReadinessSnapshot>,
+    #[serde(default)]
+    monthly_rent: Option<u32>,
+}
+
+impl TryFrom<StoredWorkflowInstance> for VacancyWorkflowInstance {
+    type Error = String;
+
+    /// Rejects an `order` that is not a permutation of the task positions, which would
+    /// otherwise index past `tasks` or skip tasks when reports walk dependency order.
+    fn try_from(stored: StoredWorkflowInstance) -> Result<Self, Self::Error> {
+        let mut seen = vec![false; stored.tasks.len()];
+        for &position in &stored.order {
+            match seen.get_mut(position) {
+                Some(seen) if !*seen => *seen = true,
+                Some(_) => return Err(format!("task order repeats position {}", position)),
+                None => {
+                    return Err(format!(
+                        "task order position {} is out of range for {} tasks",
+                        position,
+                        stored.tasks.len()
+                    ))
+                }
+            }
+        }
+        if stored.order.len() != stored.tasks.len() {
+            return Err(format!(
+                "task order covers {} of {} tasks",
+                stored.order.len(),
+                stored.tasks.len()
+            ));
+        }
+
+        Ok(Self {
+            vacancy_start: stored.vacancy_start,
+            target_move_in: stored.target_move_in,
+            calendar: stored.calendar,
+            tasks: stored.tasks,
+            order: stored.order,
+            schedule_changes: stored.schedule_changes,
+            severity_policy: stored.severity_policy,
+            snapshots: stored.snapshots,
+            monthly_rent: stored.monthly_rent,
+        })
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskInstance {
     pub template: TaskTemplate,
     pub due_date: NaiveDate,
     pub status: TaskStatus,
     pub completed_on: Option<NaiveDate>,
+    /// Staff id from the [`StaffRoster`] responsible for the task.
+    #[serde(default)]
+    pub assignee: Option<String>,
+    /// Checklist state for the template deliverables, in template order.
+    #[serde(default)]
+    subtasks: Vec<Subtask>,
+    #[serde(default)]
+    evidence: Vec<ComplianceEvidence>,
+    #[serde(default)]
+    history: Vec<TaskEvent>,
 }
 
 impl TaskInstance {
+    /// Status transitions recorded for this task, oldest first.
+    pub fn history(&self) -> &[TaskEvent] {
+        &self.history
+    }
+
+    pub fn subtasks(&self) -> &[Subtask] {
+        &self.subtasks
+    }
+
+    /// Evidence attached to the task's compliance notes, in the order it was recorded.
+    pub fn evidence(&self) -> &[ComplianceEvidence] {
+        &self.evidence
+    }
+
+    pub fn has_evidence_for(&self, topic: &str) -> bool {
+        self.evidence.iter().any(|evidence| evidence.topic == topic)
+    }
+
+    /// Fraction of the task that is done: 1.0 once completed, otherwise the share of
+    /// required subtasks checked off (0.0 when the task has none).
+    pub fn progress(&self) -> f32 {
+        if self.status == TaskStatus::Completed {
+            return 1.0;
+        }
+        let required = self
+            .subtasks
+            .iter()
+            .filter(|subtask| subtask.required)
+            .count();
+        if required == 0 {
+            return 0.0;
+        }
+        let completed = self
+            .subtasks
+            .iter()
+            .filter(|subtask| subtask.required && subtask.completed)
+            .count();
+        completed as f32 / required as f32
+    }
+
+    /// Whether the task has required subtasks and every one of them is checked off.
+    fn required_subtasks_complete(&self) -> bool {
+        let mut required = self.subtasks.iter().filter(|subtask| subtask.required);
+        required.clone().next().is_some() && required.all(|subtask| subtask.completed)
+    }
+
     pub fn to_view(&self) -> TaskDetailView {
         TaskDetailView {
-            key: self.template.key,
-            name: self.template.name,
+            key: self.template.key.clone(),
+            name: self.template.name.clone(),
             stage: self.template.stage,
             stage_label: self.template.stage.label(),
             role: self.template.primary_role,
@@ -49,41 +200,293 @@ impl TaskInstance {
             status: self.status,
             status_label: self.status.label(),
             completed_on: self.completed_on,
-            deliverables: self.template.deliverables.clone(),
+            assignee: self.assignee.clone(),
+            deliverables: self
+                .subtasks
+                .iter()
+                .map(|subtask| subtask.name.clone())
+                .collect(),
+            subtasks: self.subtasks.clone(),
             compliance: self.template.compliance.clone(),
+            evidence: self.evidence.clone(),
+            prerequisites: self.template.prerequisites.clone(),
+            waiting_on: Vec::new(),
+            history: self.history.clone(),
+        }
+    }
+
+    fn snapshot(&self, waiting_on: Vec<String>) -> TaskSnapshot {
+        TaskSnapshot {
+            key: self.template.key.clone(),
+            name: self.template.name.clone(),
+            stage: self.template.stage,
+            role: self.template.primary_role,
+            due_date: self.due_date,
+            status: self.status,
+            assignee: self.assignee.clone(),
+            waiting_on,
         }
     }
 }
 
 impl VacancyWorkflowInstance {
+    /// Rejects a vacancy window whose target move-in falls before the vacancy start.
+    /// The constructors do not check the window, so callers storing a vacancy run this
+    /// first; [`Self::reschedule`] applies it to every new window.
+    pub fn check_schedule(
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+    ) -> Result<(), VacancyError> {
+        if target_move_in < vacancy_start {
+            return Err(VacancyError::InvalidSchedule {
+                vacancy_start,
+                target_move_in,
+            });
+        }
+        Ok(())
+    }
+
     pub fn new(
         blueprint: &VacancyWorkflowBlueprint,
         vacancy_start: NaiveDate,
         target_move_in: NaiveDate,
+    ) -> Self {
+        Self::with_calendar(
+            blueprint,
+            vacancy_start,
+            target_move_in,
+            BusinessCalendar::default(),
+        )
+    }
+
+    /// Instantiates the blueprint, resolving business-day rules and overdue checks
+    /// against the supplied holiday calendar.
+    pub fn with_calendar(
+        blueprint: &VacancyWorkflowBlueprint,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+        calendar: BusinessCalendar,
     ) -> Self {
         let tasks = blueprint
             .task_templates()
             .iter()
             .cloned()
             .map(|template| {
-                let due_date = template.due.resolve(vacancy_start, target_move_in);
+                let due_date = template
+                    .due
+                    .resolve(vacancy_start, target_move_in, &calendar);
                 TaskInstance {
+                    subtasks: template.deliverables.iter().map(Subtask::from).collect(),
                     template,
                     due_date,
                     status: TaskStatus::NotStarted,
                     completed_on: None,
+                    assignee: None,
+                    evidence: Vec::new(),
+                    history: Vec::new(),
                 }
             })
             .collect();
 
-        Self { tasks }
+        Self {
+            vacancy_start,
+            target_move_in,
+            calendar,
+            tasks,
+            order: blueprint.task_order().to_vec(),
+            schedule_changes: Vec::new(),
+            severity_policy: SeverityPolicy::default(),
+            snapshots: Vec::new(),
+            monthly_rent: None,
+        }
+    }
+
+    pub fn calendar(&self) -> &BusinessCalendar {
+        &self.calendar
+    }
+
+    /// Swaps the holiday calendar and re-resolves every due date against it.
+    pub fn set_calendar(&mut self, calendar: BusinessCalendar) {
+        for task in &mut self.tasks {
+            task.due_date =
+                task.template
+                    .due
+                    .resolve(self.vacancy_start, self.target_move_in, &calendar);
+        }
+        self.calendar = calendar;
+    }
+
+    pub fn severity_policy(&self) -> &SeverityPolicy {
+        &self.severity_policy
+    }
+
+    /// Replaces the policy used to grade compliance alerts on open tasks.
+    pub fn set_severity_policy(&mut self, policy: SeverityPolicy) {
+        self.severity_policy = policy;
+    }
+
+    /// Typed automation for the vacancy as of `today`, ready for an
+    /// [`super::AutomationDispatcher`].
+    pub fn automation_triggers(&self, today: NaiveDate) -> Vec<AutomationTrigger> {
+        plan_triggers(&self.report(today).summary(), self)
+    }
+
+    pub fn monthly_rent(&self) -> Option<u32> {
+        self.monthly_rent
+    }
+
+    pub fn set_monthly_rent(&mut self, monthly_rent: Option<u32>) {
+        self.monthly_rent = monthly_rent;
+    }
+
+    /// Prices the vacancy at the listing's advertised rent.
+    pub fn apply_listing(&mut self, listing: &VacancyListingSnapshot) {
+        self.monthly_rent = Some(listing.listed_rent);
+    }
+
+    /// Whether the task is still open after its due date, per the business calendar.
+    pub fn is_overdue(&self, task: &TaskInstance, today: NaiveDate) -> bool {
+        task.status != TaskStatus::Completed && self.calendar.is_overdue(task.due_date, today)
+    }
+
+    /// Moves the vacancy window and re-resolves every due date, keeping task status and
+    /// history. The shifted due dates are appended to [`Self::schedule_changes`]; an
+    /// unchanged window records nothing and returns `None`.
+    pub fn reschedule(
+        &mut self,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+    ) -> Result<Option<&ScheduleChange>, VacancyError> {
+        Self::check_schedule(vacancy_start, target_move_in)?;
+        if vacancy_start == self.vacancy_start && target_move_in == self.target_move_in {
+            return Ok(None);
+        }
+
+        let mut shifted_tasks = Vec::new();
+        for task in &mut self.tasks {
+            let due_date = task
+                .template
+                .due
+                .resolve(vacancy_start, target_move_in, &self.calendar);
+            if due_date != task.due_date {
+                shifted_tasks.push(DueDateShift {
+                    task_key: task.template.key.clone(),
+                    previous_due_date: task.due_date,
+                    due_date,
+                });
+                task.due_date = due_date;
+            }
+        }
+
+        self.schedule_changes.push(ScheduleChange {
+            recorded_at: Utc::now(),
+            previous_vacancy_start: self.vacancy_start,
+            vacancy_start,
+            previous_target_move_in: self.target_move_in,
+            target_move_in,
+            shifted_tasks,
+        });
+        self.vacancy_start = vacancy_start;
+        self.target_move_in = target_move_in;
+
+        Ok(self.schedule_changes.last())
+    }
+
+    /// Reschedules applied to this vacancy, oldest first.
+    pub fn schedule_changes(&self) -> &[ScheduleChange] {
+        &self.schedule_changes
+    }
+
+    /// Captures readiness as of `date`, replacing any snapshot already taken that day.
+    pub fn record_snapshot(&mut self, date: NaiveDate) -> &ReadinessSnapshot {
+        let snapshot = ReadinessSnapshot::capture(self, date);
+        let position = match self
+            .snapshots
+            .binary_search_by_key(&date, |existing| existing.date)
+        {
+            Ok(position) => {
+                self.snapshots[position] = snapshot;
+                position
+            }
+            Err(position) => {
+                self.snapshots.insert(position, snapshot);
+                position
+            }
+        };
+        &self.snapshots[position]
+    }
+
+    pub fn snapshots(&self) -> &[ReadinessSnapshot] {
+        &self.snapshots
+    }
+
+    pub fn readiness_trend(&self) -> ReadinessTrend {
+        ReadinessTrend::from_snapshots(&self.snapshots)
+    }
+
+    pub fn vacancy_start(&self) -> NaiveDate {
+        self.vacancy_start
+    }
+
+    pub fn target_move_in(&self) -> NaiveDate {
+        self.target_move_in
+    }
+
+    /// Computes earliest/latest dates and slack against the target move-in for every
+    /// open task, flagging the tasks on the critical path.
+    pub fn schedule(&self, today: NaiveDate) -> Vec<TaskScheduleView> {
+        schedule::compute(
+            &self.tasks,
+            &self.order,
+            &self.calendar,
+            self.vacancy_start,
+            self.target_move_in,
+            today,
+        )
     }
 
+    /// Updates a task's status, rejecting starts or completions while any
+    /// prerequisite task is still incomplete.
     pub fn set_status(
         &mut self,
         task_key: &str,
         status: TaskStatus,
         completed_on: Option<NaiveDate>,
+    ) -> Result<(), VacancyError> {
+        self.apply_status_change(task_key, StatusChange::new(status, completed_on))
+    }
+
+    /// Applies a status change and appends it, with actor and note, to the task history.
+    pub fn apply_status_change(
+        &mut self,
+        task_key: &str,
+        change: StatusChange,
+    ) -> Result<(), VacancyError> {
+        if matches!(
+            change.status,
+            TaskStatus::InProgress | TaskStatus::Completed
+        ) {
+            let task = self.task(task_key)?;
+            let pending = self.pending_prerequisites(task);
+            if !pending.is_empty() {
+                return Err(VacancyError::PrerequisitesIncomplete {
+                    task_key: task_key.to_owned(),
+                    pending,
+                });
+            }
+        }
+
+        self.record_status(task_key, change)
+    }
+
+    /// Overwrites a task's status without checking prerequisites. Importers use this
+    /// to mirror an external system of record that may complete work out of order.
+    /// Completing a task checks off its open subtasks; moving it out of Completed
+    /// unchecks those again, leaving subtasks checked off individually in place.
+    pub(crate) fn record_status(
+        &mut self,
+        task_key: &str,
+        change: StatusChange,
     ) -> Result<(), VacancyError> {
         let task = self
             .tasks
@@ -91,17 +494,201 @@ impl VacancyWorkflowInstance {
             .find(|instance| instance.template.key == task_key)
             .ok_or_else(|| VacancyError::TaskNotFound(task_key.to_owned()))?;
 
-        task.status = status;
-        task.completed_on = match status {
-            TaskStatus::Completed => completed_on,
+        let completed_on = match change.status {
+            TaskStatus::Completed => change.completed_on,
+            _ => None,
+        };
+        let reopened =
+            task.status == TaskStatus::Completed && change.status != TaskStatus::Completed;
+        task.history.push(TaskEvent {
+            recorded_at: change.recorded_at,
+            from_status: task.status,
+            to_status: change.status,
+            completed_on,
+            actor: change.actor,
+            note: change.note,
+        });
+        task.status = change.status;
+        task.completed_on = completed_on;
+
+        if task.status == TaskStatus::Completed {
+            for subtask in task
+                .subtasks
+                .iter_mut()
+                .filter(|subtask| !subtask.completed)
+            {
+                subtask.completed = true;
+                subtask.completed_on = completed_on;
+                subtask.checked_by_completion = true;
+            }
+        } else if reopened {
+            for subtask in task
+                .subtasks
+                .iter_mut()
+                .filter(|subtask| subtask.checked_by_completion)
+            {
+                subtask.completed = false;
+                subtask.completed_on = None;
+                subtask.checked_by_completion = false;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks or unchecks one subtask (by position in the deliverables list) and keeps
+    /// the task status in line: checking the last required subtask completes the task,
+    /// checking any subtask starts it, and reopening one on a completed task moves it
+    /// back to in progress.
+    pub fn set_subtask(
+        &mut self,
+        task_key: &str,
+        index: usize,
+        completed: bool,
+        completed_on: Option<NaiveDate>,
+    ) -> Result<(), VacancyError> {
+        let task = self.task(task_key)?;
+        if index >= task.subtasks.len() {
+            return Err(VacancyError::SubtaskNotFound {
+                task_key: task_key.to_owned(),
+                index,
+            });
+        }
+        if completed {
+            let pending = self.pending_prerequisites(task);
+            if !pending.is_empty() {
+                return Err(VacancyError::PrerequisitesIncomplete {
+                    task_key: task_key.to_owned(),
+                    pending,
+                });
+            }
+        }
+
+        let task = self
+            .tasks
+            .iter_mut()
+            .find(|instance| instance.template.key == task_key)
+            .expect("task was found above");
+        let subtask = &mut task.subtasks[index];
+        subtask.completed = completed;
+        subtask.completed_on = if completed { completed_on } else { None };
+        subtask.checked_by_completion = false;
+        let subtask_name = subtask.name.clone();
+
+        let all_required = task.required_subtasks_complete();
+        let derived = match task.status {
+            TaskStatus::Completed if !completed => Some((
+                TaskStatus::InProgress,
+                format!("Subtask reopened: {}", subtask_name),
+            )),
+            TaskStatus::Completed => None,
+            _ if completed && all_required => Some((
+                TaskStatus::Completed,
+                "All required subtasks complete".to_string(),
+            )),
+            TaskStatus::NotStarted if completed => Some((
+                TaskStatus::InProgress,
+                format!("Subtask completed: {}", subtask_name),
+            )),
             _ => None,
         };
 
+        if let Some((status, note)) = derived {
+            let completed_on = match status {
+                TaskStatus::Completed => task
+                    .subtasks
+                    .iter()
+                    .filter_map(|subtask| subtask.completed_on)
+                    .max()
+                    .or(completed_on),
+                _ => None,
+            };
+            let mut change = StatusChange::new(status, completed_on);
+            change.note = Some(note);
+            self.record_status(task_key, change)?;
+        }
+
+        Ok(())
+    }
+
+    /// Records evidence against the compliance note on `task_key` whose topic matches.
+    pub fn attach_evidence(
+        &mut self,
+        task_key: &str,
+        evidence: ComplianceEvidence,
+    ) -> Result<(), VacancyError> {
+        let task = self
+            .tasks
+            .iter_mut()
+            .find(|instance| instance.template.key == task_key)
+            .ok_or_else(|| VacancyError::TaskNotFound(task_key.to_owned()))?;
+        if !task
+            .template
+            .compliance
+            .iter()
+            .any(|note| note.topic == evidence.topic)
+        {
+            return Err(VacancyError::UnknownComplianceNote {
+                task_key: task_key.to_owned(),
+                topic: evidence.topic,
+            });
+        }
+        task.evidence.push(evidence);
+        Ok(())
+    }
+
+    /// Sets or clears the staff member responsible for a task.
+    pub fn assign(&mut self, task_key: &str, assignee: Option<&str>) -> Result<(), VacancyError> {
+        let task = self
+            .tasks
+            .iter_mut()
+            .find(|instance| instance.template.key == task_key)
+            .ok_or_else(|| VacancyError::TaskNotFound(task_key.to_owned()))?;
+        task.assignee = assignee.map(str::to_owned);
         Ok(())
     }
 
+    /// Per-person load for this vacancy plus rebalancing suggestions within the roster.
+    pub fn staffing(&self, roster: &StaffRoster, today: NaiveDate) -> StaffingReport {
+        staffing_report([(None, self)], roster, today)
+    }
+
+    pub(crate) fn tasks_in_dependency_order(&self) -> impl Iterator<Item = &TaskInstance> + '_ {
+        self.order.iter().map(|&position| &self.tasks[position])
+    }
+
+    /// Returns the recorded status transitions for a task, oldest first.
+    pub fn task_history(&self, task_key: &str) -> Result<&[TaskEvent], VacancyError> {
+        self.task(task_key).map(TaskInstance::history)
+    }
+
+    /// Returns the keys of prerequisites that have not been completed yet.
+    pub fn pending_prerequisites(&self, task: &TaskInstance) -> Vec<String> {
+        task.template
+            .prerequisites
+            .iter()
+            .filter(|key| {
+                self.tasks
+                    .iter()
+                    .find(|candidate| candidate.template.key == **key)
+                    .is_some_and(|candidate| candidate.status != TaskStatus::Completed)
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn task(&self, task_key: &str) -> Result<&TaskInstance, VacancyError> {
+        self.tasks
+            .iter()
+            .find(|instance| instance.template.key == task_key)
+            .ok_or_else(|| VacancyError::TaskNotFound(task_key.to_owned()))
+    }
+
     pub fn report(&self, today: NaiveDate) -> VacancyReport {
-        let mut report = VacancyReport::default();
+        let mut report = VacancyReport {
+            schedule_changes: self.schedule_changes.clone(),
+            ..VacancyReport::default()
+        };
 
         for task in &self.tasks {
             let stage_entry = report
@@ -119,44 +706,64 @@ impl VacancyWorkflowInstance {
                 .or_default();
             if task.status != TaskStatus::Completed {
                 role_entry.open += 1;
-                if task.due_date < today {
+                if self.is_overdue(task, today) {
                     role_entry.overdue += 1;
                 }
-            }
 
-            if task.status != TaskStatus::Completed && task.due_date < today {
-                report.overdue_tasks.push(TaskSnapshot {
-                    key: task.template.key,
-                    name: task.template.name,
-                    stage: task.template.stage,
-                    role: task.template.primary_role,
-                    due_date: task.due_date,
-                    status: task.status,
-                });
-
-                for note in &task.template.compliance {
-                    report.compliance_alerts.push(ComplianceAlert {
-                        task_key: task.template.key,
-                        topic: note.topic,
-                        detail: note.detail,
-                        severity: ComplianceSeverity::Critical,
-                    });
+                if let Some(assignee) = &task.assignee {
+                    let staff_entry = report.staff_load.entry(assignee.clone()).or_default();
+                    staff_entry.open += 1;
+                    if self.is_overdue(task, today) {
+                        staff_entry.overdue += 1;
+                    }
                 }
-            } else if task.status != TaskStatus::Completed && !task.template.compliance.is_empty() {
-                for note in &task.template.compliance {
-                    report.compliance_alerts.push(ComplianceAlert {
-                        task_key: task.template.key,
-                        topic: note.topic,
-                        detail: note.detail,
-                        severity: ComplianceSeverity::Warning,
-                    });
+            }
+
+            if task.status != TaskStatus::Completed {
+                let waiting_on = self.pending_prerequisites(task);
+                if !waiting_on.is_empty() {
+                    report.waiting_tasks.push(task.snapshot(waiting_on));
+                } else if task.status != TaskStatus::Blocked {
+                    report.actionable_tasks.push(task.snapshot(Vec::new()));
                 }
             }
+
+            let overdue = self.is_overdue(task, today);
+            if overdue {
+                report
+                    .overdue_tasks
+                    .push(task.snapshot(self.pending_prerequisites(task)));
+            }
+
+            for note in &task.template.compliance {
+                let has_evidence = task.has_evidence_for(&note.topic);
+                let (severity, detail) = if task.status == TaskStatus::Completed {
+                    if !note.evidence_required || has_evidence {
+                        continue;
+                    }
+                    (
+                        ComplianceSeverity::Critical,
+                        format!("Completed without evidence on file. {}", note.detail),
+                    )
+                } else {
+                    let days_until_due = (task.due_date - today).num_days();
+                    let severity =
+                        self.severity_policy
+                            .classify(note, days_until_due, overdue, has_evidence);
+                    (severity, note.detail.clone())
+                };
+                report.compliance_alerts.push(ComplianceAlert {
+                    task_key: task.template.key.clone(),
+                    topic: note.topic.clone(),
+                    detail,
+                    severity,
+                });
+            }
         }
 
-        report
-            .overdue_tasks
-            .sort_by(|a, b| a.due_date.cmp(&b.due_date));
+        report.overdue_tasks.sort_by_key(|task| task.due_date);
+        report.actionable_tasks.sort_by_key(|task| task.due_date);
+        report.waiting_tasks.sort_by_key(|task| task.due_date);
 
         report
     }
@@ -166,9 +773,16 @@ impl VacancyWorkflowInstance {
     }
 
     pub fn task_details(&self) -> Vec<TaskDetailView> {
-        let mut details: Vec<TaskDetailView> =
-            self.tasks.iter().map(TaskInstance::to_view).collect();
-        details.sort_by(|a, b| a.due_date.cmp(&b.due_date));
+        let mut details: Vec<TaskDetailView> = self
+            .tasks
+            .iter()
+            .map(|task| {
+                let mut view = task.to_view();
+                view.waiting_on = self.pending_prerequisites(task);
+                view
+            })
+            .collect();
+        details.sort_by_key(|task| task.due_date);
         details
     }
 }
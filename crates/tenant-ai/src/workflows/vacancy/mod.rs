@@ -1,10 +1,32 @@
 pub mod applications;
 
+mod automation;
 mod blueprint;
+mod calendar;
+mod dependencies;
 pub mod domain;
+mod forecast;
 mod instance;
+mod registry;
 pub mod report;
+mod repository;
+mod schedule;
+mod staffing;
 
-pub use blueprint::VacancyWorkflowBlueprint;
+pub use applications::VacancyAlert;
+pub use automation::{AutomationDispatcher, AutomationTrigger, DispatchOutcome};
+pub use blueprint::{BlueprintError, BlueprintFormat, VacancyWorkflowBlueprint};
+pub use calendar::{BusinessCalendar, CalendarError};
+pub use forecast::{DurationHistory, ForecastOptions, StageForecast, VacancyForecast};
 pub use instance::{TaskDetailView, VacancyWorkflowInstance};
-pub use report::VacancyReport;
+pub use registry::BlueprintRegistry;
+pub use report::{portfolio_report, PortfolioReport, VacancyReport};
+pub use repository::{
+    FileVacancyRepository, InMemoryVacancyRepository, VacancyId, VacancyRecord,
+    VacancyWorkflowRepository,
+};
+pub use schedule::TaskScheduleView;
+pub use staffing::{
+    staffing_report, AssignmentPolicy, ReassignmentSuggestion, RosterError, StaffAssigner,
+    StaffLoadEntry, StaffMember, StaffRoster, StaffingReport, UnknownAssignee,
+};
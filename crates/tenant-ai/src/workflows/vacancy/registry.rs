@@ -0,0 +1,117 @@
+use super::blueprint::{
+    BlueprintDocument, BlueprintError, BlueprintFormat, VacancyWorkflowBlueprint,
+};
+use super::domain::VacancyError;
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::Path;
+use std::sync::OnceLock;
+
+pub(crate) const LIHTC_BLUEPRINT: &str = include_str!("../../../blueprints/lihtc.toml");
+
+/// Bundled program blueprints, listed so every base precedes the programs extending it.
+const BUILTIN_BLUEPRINTS: [(&str, &str); 4] = [
+    ("lihtc", LIHTC_BLUEPRINT),
+    (
+        "market_rate",
+        include_str!("../../../blueprints/market_rate.toml"),
+    ),
+    (
+        "section_8",
+        include_str!("../../../blueprints/section_8.toml"),
+    ),
+    (
+        "student_housing",
+        include_str!("../../../blueprints/student_housing.toml"),
+    ),
+];
+
+static BUILTIN_REGISTRY: OnceLock<BlueprintRegistry> = OnceLock::new();
+
+/// Vacancy blueprints keyed by program type (e.g. `lihtc`, `market_rate`).
+#[derive(Debug, Default, Clone)]
+pub struct BlueprintRegistry {
+    blueprints: BTreeMap<String, VacancyWorkflowBlueprint>,
+}
+
+impl BlueprintRegistry {
+    /// Program used when a caller does not pick one.
+    pub const DEFAULT_PROGRAM: &'static str = "lihtc";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry holding the bundled LIHTC, market-rate, Section 8, and student housing
+    /// blueprints, as an owned copy callers can register their own programs into.
+    pub fn builtin() -> Self {
+        Self::shared_builtin().clone()
+    }
+
+    /// The bundled registry, parsed once per process and shared by every lookup.
+    pub fn shared_builtin() -> &'static Self {
+        BUILTIN_REGISTRY.get_or_init(|| {
+            let mut registry = Self::new();
+            for (name, contents) in BUILTIN_BLUEPRINTS {
+                registry
+                    .register_reader(name, contents.as_bytes(), BlueprintFormat::Toml)
+                    .expect("bundled blueprints are valid");
+            }
+            registry
+        })
+    }
+
+    pub fn register(&mut self, name: impl Into<String>, blueprint: VacancyWorkflowBlueprint) {
+        self.blueprints.insert(name.into(), blueprint);
+    }
+
+    /// Parses a blueprint document, resolving `extends` against programs already
+    /// registered, and stores it under `name`.
+    pub fn register_reader<R: Read>(
+        &mut self,
+        name: impl Into<String>,
+        reader: R,
+        format: BlueprintFormat,
+    ) -> Result<&VacancyWorkflowBlueprint, BlueprintError> {
+        let blueprint = BlueprintDocument::parse(reader, format)?.resolve(self)?;
+        let name = name.into();
+        self.blueprints.insert(name.clone(), blueprint);
+        Ok(&self.blueprints[&name])
+    }
+
+    /// Registers a blueprint file under its file stem (`affordable_senior.toml` becomes
+    /// `affordable_senior`) and returns that name.
+    pub fn register_path<P: AsRef<Path>>(&mut self, path: P) -> Result<String, BlueprintError> {
+        let path = path.as_ref();
+        let format = BlueprintFormat::from_path(path)
+            .ok_or_else(|| BlueprintError::UnsupportedFormat(path.to_path_buf()))?;
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| BlueprintError::UnsupportedFormat(path.to_path_buf()))?
+            .to_string();
+        let file = std::fs::File::open(path)?;
+        self.register_reader(name.clone(), file, format)?;
+        Ok(name)
+    }
+
+    /// Looks up a program; `standard` is accepted as an alias for the LIHTC checklist.
+    pub fn get(&self, name: &str) -> Option<&VacancyWorkflowBlueprint> {
+        let name = match name {
+            "standard" => Self::DEFAULT_PROGRAM,
+            other => other,
+        };
+        self.blueprints.get(name)
+    }
+
+    /// Picks the named program, or [`Self::DEFAULT_PROGRAM`] when `name` is `None`.
+    pub fn select(&self, name: Option<&str>) -> Result<&VacancyWorkflowBlueprint, VacancyError> {
+        let name = name.unwrap_or(Self::DEFAULT_PROGRAM);
+        self.get(name)
+            .ok_or_else(|| VacancyError::UnknownBlueprint(name.to_string()))
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.blueprints.keys().map(String::as_str)
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// Rent lost to a vacancy, in dollars, from a monthly rent annualized over 365 days.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct VacancyLoss {
+    pub monthly_rent: u32,
+    pub daily_loss: f64,
+    /// Days the unit has been on the market as of the evaluation date.
+    pub days_on_market: u32,
+    /// Rent already lost between vacancy start and the evaluation date.
+    pub incurred_loss: f64,
+    /// Rent still expected to be lost before the target move-in.
+    pub remaining_loss: f64,
+    /// Incurred plus remaining loss; once move-in has slipped this equals the incurred loss.
+    pub projected_loss: f64,
+}
+
+impl VacancyLoss {
+    pub fn new(
+        monthly_rent: u32,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+        today: NaiveDate,
+    ) -> Self {
+        let daily_loss = f64::from(monthly_rent) * 12.0 / 365.0;
+        let days_on_market = (today - vacancy_start).num_days().max(0);
+        let vacant_days = (target_move_in - vacancy_start)
+            .num_days()
+            .max(days_on_market);
+        let incurred_loss = round_cents(daily_loss * days_on_market as f64);
+        let projected_loss = round_cents(daily_loss * vacant_days as f64);
+
+        Self {
+            monthly_rent,
+            daily_loss: round_cents(daily_loss),
+            days_on_market: u32::try_from(days_on_market).unwrap_or(u32::MAX),
+            incurred_loss,
+            remaining_loss: round_cents(projected_loss - incurred_loss),
+            projected_loss,
+        }
+    }
+}
+
+/// Combined vacancy loss across the portfolio units that have a known rent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PortfolioVacancyLoss {
+    pub units_with_rent: usize,
+    pub daily_loss: f64,
+    pub incurred_loss: f64,
+    pub projected_loss: f64,
+}
+
+impl PortfolioVacancyLoss {
+    pub(crate) fn add(&mut self, loss: &VacancyLoss) {
+        self.units_with_rent += 1;
+        self.daily_loss = round_cents(self.daily_loss + loss.daily_loss);
+        self.incurred_loss = round_cents(self.incurred_loss + loss.incurred_loss);
+        self.projected_loss = round_cents(self.projected_loss + loss.projected_loss);
+    }
+}
+
+fn round_cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
@@ -1,5 +1,9 @@
-use super::super::domain::{TaskStatus, VacancyStage};
+use super::super::automation::plan_triggers;
+use super::super::domain::TaskStatus;
 use super::super::instance::VacancyWorkflowInstance;
+use super::financials::VacancyLoss;
+use super::readiness::ReadinessScorer;
+use super::rules::{InsightContext, InsightKind, InsightRuleRegistry};
 use super::views::{ReadinessLevel, VacancyInsights, VacancyReportSummary};
 use chrono::NaiveDate;
 
@@ -9,23 +13,19 @@ pub(crate) fn generate_insights(
     vacancy_start: NaiveDate,
     target_move_in: NaiveDate,
     today: NaiveDate,
+    scorer: &dyn ReadinessScorer,
+    rules: &InsightRuleRegistry,
 ) -> VacancyInsights {
     let tasks = instance.tasks();
-    let total_tasks = tasks.len() as f32;
+    let total_tasks = tasks.len();
     let completed_tasks = tasks
         .iter()
         .filter(|task| task.status == TaskStatus::Completed)
-        .count() as f32;
-    let readiness_score = if total_tasks > 0.0 {
-        ((completed_tasks / total_tasks) * 100.0).round()
-    } else {
-        0.0
-    };
-
-    let readiness_score = readiness_score.clamp(0.0, 100.0).round() as u8;
+        .count();
+    let readiness_score = scorer.score(instance, today);
 
     let overdue_count = summary.overdue_tasks.len();
-    let open_tasks = (total_tasks - completed_tasks).max(0.0);
+    let open_tasks = total_tasks - completed_tasks;
     let days_until_move_in = (target_move_in - today)
         .num_days()
         .clamp(i64::MIN, i64::MAX);
@@ -38,7 +38,7 @@ pub(crate) fn generate_insights(
     };
 
     let expected_threshold = (expected_completion_pct * 100.0) - 10.0;
-    let at_risk_due_to_timing = days_until_move_in <= 0 && open_tasks > 0.0;
+    let at_risk_due_to_timing = days_until_move_in <= 0 && open_tasks > 0;
     let at_risk_due_to_progress = (readiness_score as f32) < expected_threshold.max(0.0);
 
     let readiness_level = if readiness_score >= 80 && overdue_count == 0 {
@@ -66,119 +66,44 @@ pub(crate) fn generate_insights(
         }
     });
 
-    let mut blockers: Vec<String> = summary
-        .overdue_tasks
-        .iter()
-        .take(3)
-        .map(|task| {
-            format!(
-                "{} ({}), overdue since {}",
-                task.name, task.role_label, task.due_date
-            )
-        })
+    let critical_path: Vec<_> = instance
+        .schedule(today)
+        .into_iter()
+        .filter(|entry| entry.critical)
         .collect();
+    let schedule_slack_days = critical_path.first().map(|entry| entry.slack_days);
+    let vacancy_loss = instance
+        .monthly_rent()
+        .map(|rent| VacancyLoss::new(rent, vacancy_start, target_move_in, today));
+
+    let findings = rules.evaluate(&InsightContext {
+        summary,
+        instance,
+        today,
+        readiness_score,
+        readiness_level,
+        expected_completion_pct,
+        days_until_move_in,
+        days_since_vacancy,
+        total_tasks,
+        completed_tasks,
+        open_tasks,
+        overdue_tasks: overdue_count,
+        focus_stage,
+        schedule_slack_days,
+        vacancy_loss: vacancy_loss.as_ref(),
+    });
 
-    if blockers.is_empty() && open_tasks > 0.0 && days_until_move_in <= 3 {
-        blockers.push("Move-in is days away with open tasks remaining".to_string());
-    }
-
-    let mut ai_observations = Vec::new();
-    if total_tasks > 0.0 {
-        ai_observations.push(format!(
-            "{} of {} tasks complete ({readiness_score}% readiness)",
-            completed_tasks as u32, total_tasks as u32
-        ));
-    }
-
-    if overdue_count > 0 {
-        ai_observations.push(format!(
-            "{} critical task(s) overdue impacting compliance",
-            overdue_count
-        ));
-    }
-
-    if readiness_score as f32 + 5.0 < expected_completion_pct * 100.0 {
-        ai_observations.push(format!(
-            "Progress is {:.0}% below expected pace for this vacancy window",
-            (expected_completion_pct * 100.0 - readiness_score as f32).round()
-        ));
-    }
-
-    if days_until_move_in <= 7 {
-        ai_observations.push(format!(
-            "{} day(s) until target move-in; prioritize move-in readiness",
-            days_until_move_in.max(0)
-        ));
-    }
-
-    let mut recommended_actions = Vec::new();
-    if let Some(entry) = focus_stage {
-        let outstanding = entry.total.saturating_sub(entry.completed);
-        if outstanding > 0 {
-            recommended_actions.push(format!(
-                "Concentrate automation on {} ({} open item{})",
-                entry.stage_label,
-                outstanding,
-                if outstanding == 1 { "" } else { "s" }
-            ));
-        }
-
-        match entry.stage {
-            VacancyStage::MarketingAndAdvertising => {
-                recommended_actions.push(
-                    "Refresh listing creative and auto-respond to new leads via SMS & email"
-                        .to_string(),
-                );
-            }
-            VacancyStage::ScreeningAndApplication => {
-                recommended_actions.push(
-                    "Trigger AI-driven applicant nudges and status updates across channels"
-                        .to_string(),
-                );
-            }
-            VacancyStage::LeaseSigningAndMoveIn => {
-                recommended_actions.push(
-                    "Bundle lease packet tasks and push DocuSign reminders automatically"
-                        .to_string(),
-                );
-            }
-            VacancyStage::Handoff => {
-                recommended_actions
-                    .push("Send welcome workflow kickoff with onboarding checklist".to_string());
-            }
-        }
-    }
-
-    if !summary.compliance_alerts.is_empty() {
-        recommended_actions.push(
-            "Escalate compliance checklist to coordinator with documented follow-up".to_string(),
-        );
-    }
-
-    if days_until_move_in <= 5 && open_tasks > 0.0 {
-        recommended_actions.push(
-            "Schedule daily readiness standups until move-in blockers are cleared".to_string(),
-        );
-    }
-
-    let mut automation_triggers = Vec::new();
-    for entry in &summary.stage_progress {
-        let outstanding = entry.total.saturating_sub(entry.completed);
-        if outstanding > 0 {
-            automation_triggers.push(format!(
-                "Auto-remind {} owners of {} remaining task{}",
-                entry.stage_label,
-                outstanding,
-                if outstanding == 1 { "" } else { "s" }
-            ));
-        }
-    }
-
-    if overdue_count > 0 {
-        automation_triggers.push(
-            "Dispatch compliance alerts to AppFolio task queues for overdue work".to_string(),
-        );
-    }
+    let messages = |kind: InsightKind| -> Vec<String> {
+        findings
+            .iter()
+            .filter(|finding| finding.kind == kind)
+            .map(|finding| finding.message.clone())
+            .collect()
+    };
+    let blockers = messages(InsightKind::Blocker);
+    let mut ai_observations = messages(InsightKind::Observation);
+    let recommended_actions = messages(InsightKind::Recommendation);
 
     if ai_observations.is_empty() {
         ai_observations
@@ -193,9 +118,14 @@ pub(crate) fn generate_insights(
         days_since_vacancy: days_since_vacancy.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
         focus_stage: focus_stage_label,
         focus_stage_completion,
+        schedule_slack_days: schedule_slack_days
+            .map(|slack| slack.clamp(i32::MIN as i64, i32::MAX as i64) as i32),
+        vacancy_loss,
+        critical_path,
         blockers,
         ai_observations,
         recommended_actions,
-        automation_triggers,
+        automation_triggers: plan_triggers(summary, instance),
+        findings,
     }
 }
@@ -1,8 +1,21 @@
+mod financials;
 mod insights;
+mod portfolio;
+mod readiness;
+mod rules;
 mod summary;
+mod trend;
 pub mod views;
 
+pub use financials::{PortfolioVacancyLoss, VacancyLoss};
+pub use portfolio::{
+    portfolio_report, PortfolioOverdueTask, PortfolioReport, PortfolioUnitView, PropertyRiskEntry,
+    DEFAULT_PORTFOLIO_OVERDUE_LIMIT,
+};
+pub use readiness::{CriticalityScorer, ReadinessModel, ReadinessScorer, TaskCompletionScorer};
+pub use rules::{InsightContext, InsightFinding, InsightKind, InsightRule, InsightRuleRegistry};
 pub use summary::VacancyReport;
+pub use trend::{LevelTransition, ReadinessSnapshot, ReadinessTrend};
 
 pub(crate) use insights::generate_insights;
 pub(crate) use summary::{ComplianceAlert, TaskSnapshot};
@@ -0,0 +1,216 @@
+use super::super::domain::{TaskStatus, VacancyRole, VacancyStage};
+use super::super::repository::VacancyRecord;
+use super::financials::{PortfolioVacancyLoss, VacancyLoss};
+use super::summary::{RoleLoad, StageProgress};
+use super::views::{ReadinessLevel, RoleLoadEntry, StageProgressEntry, TaskSnapshotView};
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Number of overdue tasks listed portfolio-wide when callers do not choose a limit.
+pub const DEFAULT_PORTFOLIO_OVERDUE_LIMIT: usize = 10;
+
+/// Portfolio-wide rollup across every open vacancy.
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioReport {
+    pub today: NaiveDate,
+    pub open_vacancies: usize,
+    pub stage_progress: Vec<StageProgressEntry>,
+    pub role_load: Vec<RoleLoadEntry>,
+    /// Units ordered least-ready first, ties broken by the soonest move-in.
+    pub units: Vec<PortfolioUnitView>,
+    pub properties: Vec<PropertyRiskEntry>,
+    pub top_overdue_tasks: Vec<PortfolioOverdueTask>,
+    /// Mean days on market across open vacancies; zero when there are none.
+    pub average_days_on_market: f32,
+    pub vacancy_loss: PortfolioVacancyLoss,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioUnitView {
+    pub rank: usize,
+    pub vacancy_id: String,
+    pub property_code: String,
+    pub unit_id: String,
+    pub target_move_in: NaiveDate,
+    pub readiness_score: u8,
+    pub readiness_level: ReadinessLevel,
+    pub readiness_label: &'static str,
+    pub days_until_move_in: i32,
+    pub days_on_market: u32,
+    pub overdue_tasks: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vacancy_loss: Option<VacancyLoss>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PropertyRiskEntry {
+    pub property_code: String,
+    pub open_vacancies: usize,
+    pub at_risk: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioOverdueTask {
+    pub vacancy_id: String,
+    pub property_code: String,
+    pub unit_id: String,
+    pub days_overdue: i64,
+    #[serde(flatten)]
+    pub task: TaskSnapshotView,
+}
+
+/// Rolls up the open vacancies in `records` as of `today`.
+///
+/// Vacancies whose tasks are all complete are skipped. At most `overdue_limit` overdue
+/// tasks are listed, oldest due date first.
+pub fn portfolio_report(
+    records: &[VacancyRecord],
+    today: NaiveDate,
+    overdue_limit: usize,
+) -> PortfolioReport {
+    let mut stage_totals: HashMap<VacancyStage, StageProgress> = HashMap::new();
+    let mut role_totals: HashMap<VacancyRole, RoleLoad> = HashMap::new();
+    let mut properties: BTreeMap<String, PropertyRiskEntry> = BTreeMap::new();
+    let mut units = Vec::new();
+    let mut overdue = Vec::new();
+    let mut vacancy_loss = PortfolioVacancyLoss::default();
+
+    let open_records = records.iter().filter(|record| {
+        record
+            .instance
+            .tasks()
+            .iter()
+            .any(|task| task.status != TaskStatus::Completed)
+    });
+
+    for record in open_records {
+        let instance = &record.instance;
+        let report = instance.report(today);
+
+        for (stage, progress) in &report.stage_progress {
+            let entry = stage_totals.entry(*stage).or_default();
+            entry.completed += progress.completed;
+            entry.total += progress.total;
+        }
+        for (role, load) in &report.role_load {
+            let entry = role_totals.entry(*role).or_default();
+            entry.open += load.open;
+            entry.overdue += load.overdue;
+        }
+
+        let summary = report.summary();
+        let insights = summary.insights(
+            instance,
+            instance.vacancy_start(),
+            instance.target_move_in(),
+            today,
+        );
+
+        let property = properties
+            .entry(record.id.property_code.clone())
+            .or_insert_with(|| PropertyRiskEntry {
+                property_code: record.id.property_code.clone(),
+                open_vacancies: 0,
+                at_risk: 0,
+            });
+        property.open_vacancies += 1;
+        if insights.readiness_level == ReadinessLevel::AtRisk {
+            property.at_risk += 1;
+        }
+
+        if let Some(loss) = &insights.vacancy_loss {
+            vacancy_loss.add(loss);
+        }
+
+        let vacancy_id = record.id.to_string();
+        units.push(PortfolioUnitView {
+            rank: 0,
+            vacancy_id: vacancy_id.clone(),
+            property_code: record.id.property_code.clone(),
+            unit_id: record.id.unit_id.clone(),
+            target_move_in: instance.target_move_in(),
+            readiness_score: insights.readiness_score,
+            readiness_level: insights.readiness_level,
+            readiness_label: insights.readiness_level.label(),
+            days_until_move_in: insights.days_until_move_in,
+            days_on_market: u32::try_from(insights.days_since_vacancy.max(0)).unwrap_or(0),
+            overdue_tasks: summary.overdue_tasks.len(),
+            vacancy_loss: insights.vacancy_loss,
+        });
+
+        for task in summary.overdue_tasks {
+            overdue.push(PortfolioOverdueTask {
+                vacancy_id: vacancy_id.clone(),
+                property_code: record.id.property_code.clone(),
+                unit_id: record.id.unit_id.clone(),
+                days_overdue: (today - task.due_date).num_days(),
+                task,
+            });
+        }
+    }
+
+    units.sort_by(|left, right| {
+        left.readiness_score
+            .cmp(&right.readiness_score)
+            .then(left.days_until_move_in.cmp(&right.days_until_move_in))
+            .then_with(|| left.vacancy_id.cmp(&right.vacancy_id))
+    });
+    for (position, unit) in units.iter_mut().enumerate() {
+        unit.rank = position + 1;
+    }
+
+    overdue.sort_by(|left, right| {
+        right
+            .days_overdue
+            .cmp(&left.days_overdue)
+            .then_with(|| left.vacancy_id.cmp(&right.vacancy_id))
+    });
+    overdue.truncate(overdue_limit);
+
+    let stage_progress = VacancyStage::ordered()
+        .into_iter()
+        .filter_map(|stage| {
+            stage_totals.get(&stage).map(|progress| StageProgressEntry {
+                stage,
+                stage_label: stage.label(),
+                completed: progress.completed,
+                total: progress.total,
+            })
+        })
+        .collect();
+
+    let role_load = VacancyRole::ordered()
+        .into_iter()
+        .filter_map(|role| {
+            role_totals.get(&role).map(|load| RoleLoadEntry {
+                role,
+                role_label: role.label(),
+                open: load.open,
+                overdue: load.overdue,
+            })
+        })
+        .collect();
+
+    let average_days_on_market = if units.is_empty() {
+        0.0
+    } else {
+        units
+            .iter()
+            .map(|unit| unit.days_on_market as f32)
+            .sum::<f32>()
+            / units.len() as f32
+    };
+
+    PortfolioReport {
+        today,
+        open_vacancies: units.len(),
+        stage_progress,
+        role_load,
+        units,
+        properties: properties.into_values().collect(),
+        top_overdue_tasks: overdue,
+        average_days_on_market,
+        vacancy_loss,
+    }
+}
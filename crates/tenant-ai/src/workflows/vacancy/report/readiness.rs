@@ -0,0 +1,123 @@
+use super::super::domain::{TaskStatus, VacancyStage};
+use super::super::instance::{TaskInstance, VacancyWorkflowInstance};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Scores how ready a vacancy is for move-in, from 0 to 100.
+pub trait ReadinessScorer {
+    fn score(&self, instance: &VacancyWorkflowInstance, today: NaiveDate) -> u8;
+}
+
+/// Average task progress with every task counting equally. This is the default model.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TaskCompletionScorer;
+
+impl ReadinessScorer for TaskCompletionScorer {
+    fn score(&self, instance: &VacancyWorkflowInstance, _today: NaiveDate) -> u8 {
+        weighted_score(instance.tasks().iter().map(|task| (1.0, task.progress())))
+    }
+}
+
+/// Weights each task by its template weight, the criticality of its stage, and, while it
+/// is open, how close it is to its due date, so late-stage and imminent work moves the
+/// score the most.
+#[derive(Debug, Clone)]
+pub struct CriticalityScorer {
+    /// Multiplier per stage; stages without an entry count as 1.0.
+    pub stage_weights: HashMap<VacancyStage, f32>,
+    /// Open tasks due within this many days ramp up to double weight; overdue open tasks
+    /// count double. Completed tasks keep their base weight.
+    pub due_window_days: u32,
+}
+
+impl CriticalityScorer {
+    pub const DEFAULT_DUE_WINDOW_DAYS: u32 = 7;
+
+    fn task_weight(&self, task: &TaskInstance, today: NaiveDate) -> f32 {
+        let stage = self
+            .stage_weights
+            .get(&task.template.stage)
+            .copied()
+            .unwrap_or(1.0);
+        let base = task.template.weight as f32 * stage;
+        if task.status == TaskStatus::Completed {
+            return base;
+        }
+        base * self.proximity((task.due_date - today).num_days())
+    }
+
+    fn proximity(&self, days_until_due: i64) -> f32 {
+        let window = i64::from(self.due_window_days);
+        if days_until_due <= 0 {
+            2.0
+        } else if days_until_due >= window {
+            1.0
+        } else {
+            1.0 + (window - days_until_due) as f32 / window as f32
+        }
+    }
+}
+
+impl Default for CriticalityScorer {
+    fn default() -> Self {
+        Self {
+            stage_weights: HashMap::from([
+                (VacancyStage::MarketingAndAdvertising, 1.0),
+                (VacancyStage::ScreeningAndApplication, 1.5),
+                (VacancyStage::LeaseSigningAndMoveIn, 2.0),
+                (VacancyStage::Handoff, 1.0),
+            ]),
+            due_window_days: Self::DEFAULT_DUE_WINDOW_DAYS,
+        }
+    }
+}
+
+impl ReadinessScorer for CriticalityScorer {
+    fn score(&self, instance: &VacancyWorkflowInstance, today: NaiveDate) -> u8 {
+        weighted_score(
+            instance
+                .tasks()
+                .iter()
+                .map(|task| (self.task_weight(task, today), task.progress())),
+        )
+    }
+}
+
+/// Named scorer choice for request payloads and CLI flags.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessModel {
+    #[default]
+    TaskCompletion,
+    Criticality,
+}
+
+impl ReadinessModel {
+    pub fn from_key(value: &str) -> Option<Self> {
+        match value.trim() {
+            "task_completion" => Some(Self::TaskCompletion),
+            "criticality" => Some(Self::Criticality),
+            _ => None,
+        }
+    }
+
+    pub fn scorer(self) -> Box<dyn ReadinessScorer> {
+        match self {
+            Self::TaskCompletion => Box::new(TaskCompletionScorer),
+            Self::Criticality => Box::new(CriticalityScorer::default()),
+        }
+    }
+}
+
+fn weighted_score(tasks: impl Iterator<Item = (f32, f32)>) -> u8 {
+    let (weighted, total) = tasks.fold(
+        (0.0_f32, 0.0_f32),
+        |(weighted, total), (weight, progress)| (weighted + weight * progress, total + weight),
+    );
+    if total > 0.0 {
+        ((weighted / total) * 100.0).round().clamp(0.0, 100.0) as u8
+    } else {
+        0
+    }
+}
@@ -0,0 +1,436 @@
+use super::super::domain::{ComplianceSeverity, VacancyStage};
+use super::super::instance::VacancyWorkflowInstance;
+use super::financials::VacancyLoss;
+use super::views::{ReadinessLevel, StageProgressEntry, VacancyReportSummary};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// Metrics shared by every rule, computed once per insights run.
+#[derive(Debug, Clone, Copy)]
+pub struct InsightContext<'a> {
+    pub summary: &'a VacancyReportSummary,
+    pub instance: &'a VacancyWorkflowInstance,
+    pub today: NaiveDate,
+    pub readiness_score: u8,
+    pub readiness_level: ReadinessLevel,
+    /// Share of the vacancy window that has elapsed, from 0.0 to 1.0.
+    pub expected_completion_pct: f32,
+    pub days_until_move_in: i64,
+    pub days_since_vacancy: i64,
+    pub total_tasks: usize,
+    pub completed_tasks: usize,
+    pub open_tasks: usize,
+    pub overdue_tasks: usize,
+    /// Stage with the most open tasks.
+    pub focus_stage: Option<&'a StageProgressEntry>,
+    pub schedule_slack_days: Option<i64>,
+    pub vacancy_loss: Option<&'a VacancyLoss>,
+}
+
+/// Which list of [`super::views::VacancyInsights`] a finding is rendered into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InsightKind {
+    Observation,
+    Recommendation,
+    Blocker,
+}
+
+/// One piece of guidance produced by a rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InsightFinding {
+    pub rule_id: String,
+    pub kind: InsightKind,
+    pub priority: i32,
+    pub message: String,
+}
+
+/// Turns an [`InsightContext`] into zero or more findings. Implement this to add
+/// property- or program-specific guidance and register it on an [`InsightRuleRegistry`].
+pub trait InsightRule: Send + Sync {
+    /// Stable identifier, used to replace or remove the rule and to label its findings.
+    fn id(&self) -> &str;
+
+    /// Rules with a higher priority are listed first; ties keep registration order.
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Returns `(kind, message)` pairs; the registry stamps them with the rule id and priority.
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)>;
+}
+
+/// Ordered set of insight rules, keyed by id.
+#[derive(Default)]
+pub struct InsightRuleRegistry {
+    rules: Vec<Box<dyn InsightRule>>,
+}
+
+impl InsightRuleRegistry {
+    /// An empty registry; see [`Self::standard`] for the built-in rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in rules behind the default vacancy insights.
+    pub fn standard() -> Self {
+        let mut registry = Self::new();
+        registry.register(ProgressSummaryRule);
+        registry.register(OverdueTasksRule);
+        registry.register(BehindPaceRule);
+        registry.register(CriticalPathSlackRule);
+        registry.register(ScheduleChangeRule);
+        registry.register(VacancyLossRule);
+        registry.register(FocusStageRule);
+        registry.register(ComplianceBacklogRule);
+        registry.register(MoveInImminentRule);
+        registry
+    }
+
+    /// Adds `rule`, replacing any registered rule with the same id in place.
+    pub fn register<R: InsightRule + 'static>(&mut self, rule: R) -> &mut Self {
+        let rule: Box<dyn InsightRule> = Box::new(rule);
+        match self
+            .rules
+            .iter()
+            .position(|existing| existing.id() == rule.id())
+        {
+            Some(position) => self.rules[position] = rule,
+            None => self.rules.push(rule),
+        }
+        self
+    }
+
+    /// Removes the rule with `id`, returning whether one was registered.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let before = self.rules.len();
+        self.rules.retain(|rule| rule.id() != id);
+        self.rules.len() != before
+    }
+
+    pub fn rule_ids(&self) -> impl Iterator<Item = &str> + '_ {
+        self.rules.iter().map(|rule| rule.id())
+    }
+
+    /// Runs every rule and returns their findings, highest priority first.
+    pub fn evaluate(&self, context: &InsightContext<'_>) -> Vec<InsightFinding> {
+        let mut rules: Vec<&dyn InsightRule> = self.rules.iter().map(Box::as_ref).collect();
+        rules.sort_by_key(|rule| std::cmp::Reverse(rule.priority()));
+
+        rules
+            .into_iter()
+            .flat_map(|rule| {
+                rule.evaluate(context)
+                    .into_iter()
+                    .map(move |(kind, message)| InsightFinding {
+                        rule_id: rule.id().to_string(),
+                        kind,
+                        priority: rule.priority(),
+                        message,
+                    })
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Debug for InsightRuleRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.rule_ids()).finish()
+    }
+}
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+struct ProgressSummaryRule;
+
+impl InsightRule for ProgressSummaryRule {
+    fn id(&self) -> &str {
+        "progress_summary"
+    }
+
+    fn priority(&self) -> i32 {
+        95
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        if context.total_tasks == 0 {
+            return Vec::new();
+        }
+        vec![(
+            InsightKind::Observation,
+            format!(
+                "{} of {} tasks complete ({}% readiness)",
+                context.completed_tasks, context.total_tasks, context.readiness_score
+            ),
+        )]
+    }
+}
+
+/// Lists the oldest overdue tasks as blockers and routes them to AppFolio queues.
+struct OverdueTasksRule;
+
+impl InsightRule for OverdueTasksRule {
+    fn id(&self) -> &str {
+        "overdue_tasks"
+    }
+
+    fn priority(&self) -> i32 {
+        90
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        if context.overdue_tasks == 0 {
+            return Vec::new();
+        }
+
+        let mut findings: Vec<(InsightKind, String)> = context
+            .summary
+            .overdue_tasks
+            .iter()
+            .take(3)
+            .map(|task| {
+                (
+                    InsightKind::Blocker,
+                    format!(
+                        "{} ({}), overdue since {}",
+                        task.name, task.role_label, task.due_date
+                    ),
+                )
+            })
+            .collect();
+        findings.push((
+            InsightKind::Observation,
+            format!(
+                "{} critical task(s) overdue impacting compliance",
+                context.overdue_tasks
+            ),
+        ));
+        findings
+    }
+}
+
+/// Flags readiness trailing the share of the vacancy window already spent.
+struct BehindPaceRule;
+
+impl InsightRule for BehindPaceRule {
+    fn id(&self) -> &str {
+        "behind_pace"
+    }
+
+    fn priority(&self) -> i32 {
+        85
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        let expected = context.expected_completion_pct * 100.0;
+        let score = context.readiness_score as f32;
+        if score + 5.0 >= expected {
+            return Vec::new();
+        }
+        vec![(
+            InsightKind::Observation,
+            format!(
+                "Progress is {:.0}% below expected pace for this vacancy window",
+                (expected - score).round()
+            ),
+        )]
+    }
+}
+
+struct CriticalPathSlackRule;
+
+impl InsightRule for CriticalPathSlackRule {
+    fn id(&self) -> &str {
+        "critical_path_slack"
+    }
+
+    fn priority(&self) -> i32 {
+        80
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        let message = match context.schedule_slack_days {
+            Some(slack) if slack < 0 => format!(
+                "Critical path overruns target move-in by {} day(s)",
+                slack.unsigned_abs()
+            ),
+            Some(0) => "Critical path has no slack; any slip on it pushes move-in".to_string(),
+            _ => return Vec::new(),
+        };
+        vec![(InsightKind::Observation, message)]
+    }
+}
+
+struct ScheduleChangeRule;
+
+impl InsightRule for ScheduleChangeRule {
+    fn id(&self) -> &str {
+        "schedule_change"
+    }
+
+    fn priority(&self) -> i32 {
+        75
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        context
+            .summary
+            .schedule_changes
+            .last()
+            .map(|change| (InsightKind::Observation, change.summary()))
+            .into_iter()
+            .collect()
+    }
+}
+
+struct VacancyLossRule;
+
+impl InsightRule for VacancyLossRule {
+    fn id(&self) -> &str {
+        "vacancy_loss"
+    }
+
+    fn priority(&self) -> i32 {
+        70
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        context
+            .vacancy_loss
+            .map(|loss| {
+                (
+                    InsightKind::Observation,
+                    format!(
+                        "Vacancy has cost ${:.0} over {} day(s) on market; ${:.0} projected by move-in",
+                        loss.incurred_loss, loss.days_on_market, loss.projected_loss
+                    ),
+                )
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Points automation at the stage with the most open work.
+struct FocusStageRule;
+
+impl InsightRule for FocusStageRule {
+    fn id(&self) -> &str {
+        "focus_stage"
+    }
+
+    fn priority(&self) -> i32 {
+        65
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        let Some(entry) = context.focus_stage else {
+            return Vec::new();
+        };
+
+        let mut findings = Vec::new();
+        let outstanding = entry.total.saturating_sub(entry.completed);
+        if outstanding > 0 {
+            findings.push((
+                InsightKind::Recommendation,
+                format!(
+                    "Concentrate automation on {} ({} open item{})",
+                    entry.stage_label,
+                    outstanding,
+                    plural(outstanding)
+                ),
+            ));
+        }
+
+        let playbook = match entry.stage {
+            VacancyStage::MarketingAndAdvertising => {
+                "Refresh listing creative and auto-respond to new leads via SMS & email"
+            }
+            VacancyStage::ScreeningAndApplication => {
+                "Trigger AI-driven applicant nudges and status updates across channels"
+            }
+            VacancyStage::LeaseSigningAndMoveIn => {
+                "Bundle lease packet tasks and push DocuSign reminders automatically"
+            }
+            VacancyStage::Handoff => "Send welcome workflow kickoff with onboarding checklist",
+        };
+        findings.push((InsightKind::Recommendation, playbook.to_string()));
+        findings
+    }
+}
+
+/// Escalates once any compliance alert reaches Warning.
+struct ComplianceBacklogRule;
+
+impl InsightRule for ComplianceBacklogRule {
+    fn id(&self) -> &str {
+        "compliance_backlog"
+    }
+
+    fn priority(&self) -> i32 {
+        60
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        let escalate = context
+            .summary
+            .compliance_alerts
+            .iter()
+            .any(|alert| alert.severity >= ComplianceSeverity::Warning);
+        if !escalate {
+            return Vec::new();
+        }
+        vec![(
+            InsightKind::Recommendation,
+            "Escalate compliance checklist to coordinator with documented follow-up".to_string(),
+        )]
+    }
+}
+
+/// Tightens cadence in the final week before move-in.
+struct MoveInImminentRule;
+
+impl InsightRule for MoveInImminentRule {
+    fn id(&self) -> &str {
+        "move_in_imminent"
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        let days = context.days_until_move_in;
+        let open = context.open_tasks > 0;
+        let mut findings = Vec::new();
+
+        if days <= 3 && open && context.overdue_tasks == 0 {
+            findings.push((
+                InsightKind::Blocker,
+                "Move-in is days away with open tasks remaining".to_string(),
+            ));
+        }
+        if days <= 7 {
+            findings.push((
+                InsightKind::Observation,
+                format!(
+                    "{} day(s) until target move-in; prioritize move-in readiness",
+                    days.max(0)
+                ),
+            ));
+        }
+        if days <= 5 && open {
+            findings.push((
+                InsightKind::Recommendation,
+                "Schedule daily readiness standups until move-in blockers are cleared".to_string(),
+            ));
+        }
+        findings
+    }
+}
@@ -1,8 +1,13 @@
-use super::super::domain::{ComplianceSeverity, TaskStatus, VacancyRole, VacancyStage};
+use super::super::domain::{
+    ComplianceSeverity, ScheduleChange, TaskStatus, VacancyRole, VacancyStage,
+};
+use super::super::forecast::{self, DurationHistory, ForecastOptions, VacancyForecast};
 use super::super::instance::VacancyWorkflowInstance;
+use super::readiness::{ReadinessScorer, TaskCompletionScorer};
+use super::rules::InsightRuleRegistry;
 use super::views::{
-    ComplianceAlertView, RoleLoadEntry, StageProgressEntry, TaskSnapshotView, VacancyInsights,
-    VacancyReportSummary,
+    AssigneeLoadEntry, ComplianceAlertView, RoleLoadEntry, StageProgressEntry, TaskSnapshotView,
+    VacancyInsights, VacancyReportSummary,
 };
 use chrono::NaiveDate;
 use std::collections::HashMap;
@@ -23,8 +28,16 @@ pub struct RoleLoad {
 pub struct VacancyReport {
     pub stage_progress: HashMap<VacancyStage, StageProgress>,
     pub role_load: HashMap<VacancyRole, RoleLoad>,
+    /// Open and overdue counts per assigned staff id.
+    pub staff_load: HashMap<String, RoleLoad>,
     pub overdue_tasks: Vec<TaskSnapshot>,
     pub compliance_alerts: Vec<ComplianceAlert>,
+    /// Open tasks whose prerequisites are complete and can be worked right now.
+    pub actionable_tasks: Vec<TaskSnapshot>,
+    /// Open tasks still waiting on incomplete upstream work.
+    pub waiting_tasks: Vec<TaskSnapshot>,
+    /// Reschedules applied to the vacancy window, oldest first.
+    pub schedule_changes: Vec<ScheduleChange>,
 }
 
 impl VacancyReport {
@@ -55,6 +68,17 @@ impl VacancyReport {
             })
             .collect();
 
+        let mut staff_load: Vec<AssigneeLoadEntry> = self
+            .staff_load
+            .iter()
+            .map(|(assignee, load)| AssigneeLoadEntry {
+                assignee: assignee.clone(),
+                open: load.open,
+                overdue: load.overdue,
+            })
+            .collect();
+        staff_load.sort_by(|left, right| left.assignee.cmp(&right.assignee));
+
         let overdue_tasks = self
             .overdue_tasks
             .iter()
@@ -67,11 +91,27 @@ impl VacancyReport {
             .map(ComplianceAlert::to_view)
             .collect();
 
+        let actionable_tasks = self
+            .actionable_tasks
+            .iter()
+            .map(TaskSnapshot::to_view)
+            .collect();
+
+        let waiting_tasks = self
+            .waiting_tasks
+            .iter()
+            .map(TaskSnapshot::to_view)
+            .collect();
+
         VacancyReportSummary {
             stage_progress,
             role_load,
+            staff_load,
             overdue_tasks,
             compliance_alerts,
+            actionable_tasks,
+            waiting_tasks,
+            schedule_changes: self.schedule_changes.clone(),
         }
     }
 }
@@ -84,25 +124,86 @@ impl VacancyReportSummary {
         target_move_in: NaiveDate,
         today: NaiveDate,
     ) -> VacancyInsights {
-        super::generate_insights(self, instance, vacancy_start, target_move_in, today)
+        self.insights_with_scorer(
+            instance,
+            vacancy_start,
+            target_move_in,
+            today,
+            &TaskCompletionScorer,
+        )
+    }
+
+    /// Same as [`Self::insights`] but computes the readiness score with `scorer`.
+    pub fn insights_with_scorer(
+        &self,
+        instance: &VacancyWorkflowInstance,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+        today: NaiveDate,
+        scorer: &dyn ReadinessScorer,
+    ) -> VacancyInsights {
+        self.insights_with_rules(
+            instance,
+            vacancy_start,
+            target_move_in,
+            today,
+            scorer,
+            &InsightRuleRegistry::standard(),
+        )
+    }
+
+    /// Same as [`Self::insights_with_scorer`] but builds the message lists from `rules`
+    /// instead of the standard rule set.
+    pub fn insights_with_rules(
+        &self,
+        instance: &VacancyWorkflowInstance,
+        vacancy_start: NaiveDate,
+        target_move_in: NaiveDate,
+        today: NaiveDate,
+        scorer: &dyn ReadinessScorer,
+        rules: &InsightRuleRegistry,
+    ) -> VacancyInsights {
+        super::generate_insights(
+            self,
+            instance,
+            vacancy_start,
+            target_move_in,
+            today,
+            scorer,
+            rules,
+        )
+    }
+
+    /// Simulates the remaining work `options.runs` times, drawing each open task's duration
+    /// from `history` where it has enough samples and from the blueprint duration otherwise.
+    pub fn forecast(
+        &self,
+        instance: &VacancyWorkflowInstance,
+        today: NaiveDate,
+        history: &DurationHistory,
+        options: &ForecastOptions,
+    ) -> VacancyForecast {
+        forecast::simulate(instance, today, history, options)
     }
 }
 
 #[derive(Debug)]
 pub struct TaskSnapshot {
-    pub key: &'static str,
-    pub name: &'static str,
+    pub key: String,
+    pub name: String,
     pub stage: VacancyStage,
     pub role: VacancyRole,
     pub due_date: NaiveDate,
     pub status: TaskStatus,
+    pub assignee: Option<String>,
+    pub waiting_on: Vec<String>,
 }
 
 impl TaskSnapshot {
     pub fn to_view(&self) -> TaskSnapshotView {
         TaskSnapshotView {
-            key: self.key,
-            name: self.name,
+            key: self.key.clone(),
+            name: self.name.clone(),
             stage: self.stage,
             stage_label: self.stage.label(),
             role: self.role,
@@ -111,24 +212,26 @@ impl TaskSnapshot {
             status: self.status,
             status_label: self.status.label(),
             completed_on: None,
+            assignee: self.assignee.clone(),
+            waiting_on: self.waiting_on.clone(),
         }
     }
 }
 
 #[derive(Debug)]
 pub struct ComplianceAlert {
-    pub task_key: &'static str,
-    pub topic: &'static str,
-    pub detail: &'static str,
+    pub task_key: String,
+    pub topic: String,
+    pub detail: String,
     pub severity: ComplianceSeverity,
 }
 
 impl ComplianceAlert {
     pub fn to_view(&self) -> ComplianceAlertView {
         ComplianceAlertView {
-            task_key: self.task_key,
-            topic: self.topic,
-            detail: self.detail,
+            task_key: self.task_key.clone(),
+            topic: self.topic.clone(),
+            detail: self.detail.clone(),
             severity: self.severity,
             severity_label: self.severity.label(),
         }
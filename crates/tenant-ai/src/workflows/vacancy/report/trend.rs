@@ -0,0 +1,96 @@
+use super::super::domain::ComplianceSeverity;
+use super::super::instance::VacancyWorkflowInstance;
+use super::views::ReadinessLevel;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Compact record of one day's readiness, kept on the instance so trends survive restarts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessSnapshot {
+    pub date: NaiveDate,
+    pub readiness_score: u8,
+    pub readiness_level: ReadinessLevel,
+    pub completed_tasks: usize,
+    pub open_tasks: usize,
+    pub overdue_tasks: usize,
+    pub critical_alerts: usize,
+}
+
+impl ReadinessSnapshot {
+    /// Evaluates `instance` as of `date` with the default readiness model.
+    pub fn capture(instance: &VacancyWorkflowInstance, date: NaiveDate) -> Self {
+        let summary = instance.report(date).summary();
+        let insights = summary.insights(
+            instance,
+            instance.vacancy_start(),
+            instance.target_move_in(),
+            date,
+        );
+        let completed_tasks = summary
+            .stage_progress
+            .iter()
+            .map(|entry| entry.completed)
+            .sum();
+        let total_tasks: usize = summary.stage_progress.iter().map(|entry| entry.total).sum();
+
+        Self {
+            date,
+            readiness_score: insights.readiness_score,
+            readiness_level: insights.readiness_level,
+            completed_tasks,
+            open_tasks: total_tasks - completed_tasks,
+            overdue_tasks: summary.overdue_tasks.len(),
+            critical_alerts: summary
+                .compliance_alerts
+                .iter()
+                .filter(|alert| alert.severity == ComplianceSeverity::Critical)
+                .count(),
+        }
+    }
+}
+
+/// Day on which the readiness level changed between consecutive snapshots.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LevelTransition {
+    pub date: NaiveDate,
+    pub from: ReadinessLevel,
+    pub to: ReadinessLevel,
+}
+
+/// Time series of readiness snapshots, oldest first.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReadinessTrend {
+    pub points: Vec<ReadinessSnapshot>,
+    pub level_transitions: Vec<LevelTransition>,
+    /// Readiness score of the latest point minus the earliest.
+    pub score_change: i16,
+}
+
+impl ReadinessTrend {
+    pub fn from_snapshots(snapshots: &[ReadinessSnapshot]) -> Self {
+        let mut points = snapshots.to_vec();
+        points.sort_by_key(|snapshot| snapshot.date);
+
+        let level_transitions = points
+            .windows(2)
+            .filter(|pair| pair[0].readiness_level != pair[1].readiness_level)
+            .map(|pair| LevelTransition {
+                date: pair[1].date,
+                from: pair[0].readiness_level,
+                to: pair[1].readiness_level,
+            })
+            .collect();
+        let score_change = match (points.first(), points.last()) {
+            (Some(first), Some(last)) => {
+                i16::from(last.readiness_score) - i16::from(first.readiness_score)
+            }
+            _ => 0,
+        };
+
+        Self {
+            points,
+            level_transitions,
+            score_change,
+        }
+    }
+}
@@ -1,6 +1,12 @@
-use super::super::domain::{ComplianceSeverity, TaskStatus, VacancyRole, VacancyStage};
+use super::super::automation::AutomationTrigger;
+use super::super::domain::{
+    ComplianceSeverity, ScheduleChange, TaskStatus, VacancyRole, VacancyStage,
+};
+use super::super::schedule::TaskScheduleView;
+use super::financials::VacancyLoss;
+use super::rules::InsightFinding;
 use chrono::NaiveDate;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct StageProgressEntry {
@@ -18,10 +24,17 @@ pub struct RoleLoadEntry {
     pub overdue: usize,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct AssigneeLoadEntry {
+    pub assignee: String,
+    pub open: usize,
+    pub overdue: usize,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct TaskSnapshotView {
-    pub key: &'static str,
-    pub name: &'static str,
+    pub key: String,
+    pub name: String,
     pub stage: VacancyStage,
     pub stage_label: &'static str,
     pub role: VacancyRole,
@@ -30,13 +43,17 @@ pub struct TaskSnapshotView {
     pub status: TaskStatus,
     pub status_label: &'static str,
     pub completed_on: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub waiting_on: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ComplianceAlertView {
-    pub task_key: &'static str,
-    pub topic: &'static str,
-    pub detail: &'static str,
+    pub task_key: String,
+    pub topic: String,
+    pub detail: String,
     pub severity: ComplianceSeverity,
     pub severity_label: &'static str,
 }
@@ -45,11 +62,17 @@ pub struct ComplianceAlertView {
 pub struct VacancyReportSummary {
     pub stage_progress: Vec<StageProgressEntry>,
     pub role_load: Vec<RoleLoadEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub staff_load: Vec<AssigneeLoadEntry>,
     pub overdue_tasks: Vec<TaskSnapshotView>,
     pub compliance_alerts: Vec<ComplianceAlertView>,
+    pub actionable_tasks: Vec<TaskSnapshotView>,
+    pub waiting_tasks: Vec<TaskSnapshotView>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule_changes: Vec<ScheduleChange>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ReadinessLevel {
     OnTrack,
@@ -78,12 +101,24 @@ pub struct VacancyInsights {
     pub focus_stage: Option<&'static str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub focus_stage_completion: Option<f32>,
+    /// Smallest slack across open tasks; zero or negative means move-in is at risk.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_slack_days: Option<i32>,
+    /// Rent lost to the vacancy; present once the unit's monthly rent is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vacancy_loss: Option<VacancyLoss>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub critical_path: Vec<TaskScheduleView>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub blockers: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub ai_observations: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub recommended_actions: Vec<String>,
+    /// Automations to carry out, ready for [`super::super::AutomationDispatcher`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub automation_triggers: Vec<AutomationTrigger>,
+    /// Every rule finding behind the message lists above, highest priority first.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub automation_triggers: Vec<String>,
+    pub findings: Vec<InsightFinding>,
 }
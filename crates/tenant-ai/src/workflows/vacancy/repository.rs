@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use super::applications::RepositoryError;
+use super::domain::VacancyError;
+use super::instance::VacancyWorkflowInstance;
+
+/// Identifies a tracked vacancy by the property code and unit id it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct VacancyId {
+    pub property_code: String,
+    pub unit_id: String,
+}
+
+impl VacancyId {
+    /// Rejects empty parts and parts containing `:`, so every id survives the
+    /// `PROPERTY:UNIT` round trip through [`fmt::Display`] and [`VacancyId::parse`].
+    pub fn new(
+        property_code: impl Into<String>,
+        unit_id: impl Into<String>,
+    ) -> Result<Self, VacancyError> {
+        let property_code = property_code.into();
+        let unit_id = unit_id.into();
+        let valid = |part: &str| !part.is_empty() && !part.contains(':');
+        if !valid(&property_code) || !valid(&unit_id) {
+            return Err(VacancyError::InvalidVacancyId {
+                property_code,
+                unit_id,
+            });
+        }
+        Ok(Self {
+            property_code,
+            unit_id,
+        })
+    }
+
+    /// Parses the `PROPERTY:UNIT` form produced by [`fmt::Display`].
+    pub fn parse(value: &str) -> Option<Self> {
+        let (property_code, unit_id) = value.split_once(':')?;
+        Self::new(property_code, unit_id).ok()
+    }
+}
+
+impl fmt::Display for VacancyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.property_code, self.unit_id)
+    }
+}
+
+/// Repository record pairing a unit with the workflow tracking its vacancy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VacancyRecord {
+    pub id: VacancyId,
+    pub instance: VacancyWorkflowInstance,
+    /// Bumped by every successful update. An update carrying an older version than the
+    /// stored record was based on a stale read and is rejected with a conflict.
+    #[serde(default)]
+    pub version: u64,
+}
+
+/// Storage abstraction so a vacancy workflow can be tracked across its full lifetime.
+pub trait VacancyWorkflowRepository: Send + Sync {
+    fn create(&self, record: VacancyRecord) -> Result<VacancyRecord, RepositoryError>;
+    /// Replaces the stored record, failing with [`RepositoryError::Conflict`] when it was
+    /// updated since `record` was fetched.
+    fn update(&self, record: VacancyRecord) -> Result<(), RepositoryError>;
+    fn fetch(&self, id: &VacancyId) -> Result<Option<VacancyRecord>, RepositoryError>;
+    fn list_by_property(&self, property_code: &str) -> Result<Vec<VacancyRecord>, RepositoryError>;
+    fn list_all(&self) -> Result<Vec<VacancyRecord>, RepositoryError>;
+}
+
+type VacancyRecords = BTreeMap<VacancyId, VacancyRecord>;
+
+fn insert_new(records: &mut VacancyRecords, record: &VacancyRecord) -> Result<(), RepositoryError> {
+    if records.contains_key(&record.id) {
+        return Err(RepositoryError::Conflict);
+    }
+    records.insert(record.id.clone(), record.clone());
+    Ok(())
+}
+
+fn replace_existing(
+    records: &mut VacancyRecords,
+    record: VacancyRecord,
+) -> Result<(), RepositoryError> {
+    match records.get_mut(&record.id) {
+        Some(existing) if existing.version != record.version => Err(RepositoryError::Conflict),
+        Some(existing) => {
+            *existing = VacancyRecord {
+                version: record.version + 1,
+                ..record
+            };
+            Ok(())
+        }
+        None => Err(RepositoryError::NotFound),
+    }
+}
+
+fn for_property(records: &VacancyRecords, property_code: &str) -> Vec<VacancyRecord> {
+    records
+        .values()
+        .filter(|record| record.id.property_code == property_code)
+        .cloned()
+        .collect()
+}
+
+/// Process-local repository, suitable for tests and single-instance deployments.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryVacancyRepository {
+    records: Arc<Mutex<VacancyRecords>>,
+}
+
+impl InMemoryVacancyRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl VacancyWorkflowRepository for InMemoryVacancyRepository {
+    fn create(&self, record: VacancyRecord) -> Result<VacancyRecord, RepositoryError> {
+        let mut guard = self.records.lock().expect("repository mutex poisoned");
+        insert_new(&mut guard, &record)?;
+        Ok(record)
+    }
+
+    fn update(&self, record: VacancyRecord) -> Result<(), RepositoryError> {
+        let mut guard = self.records.lock().expect("repository mutex poisoned");
+        replace_existing(&mut guard, record)
+    }
+
+    fn fetch(&self, id: &VacancyId) -> Result<Option<VacancyRecord>, RepositoryError> {
+        let guard = self.records.lock().expect("repository mutex poisoned");
+        Ok(guard.get(id).cloned())
+    }
+
+    fn list_by_property(&self, property_code: &str) -> Result<Vec<VacancyRecord>, RepositoryError> {
+        let guard = self.records.lock().expect("repository mutex poisoned");
+        Ok(for_property(&guard, property_code))
+    }
+
+    fn list_all(&self) -> Result<Vec<VacancyRecord>, RepositoryError> {
+        let guard = self.records.lock().expect("repository mutex poisoned");
+        Ok(guard.values().cloned().collect())
+    }
+}
+
+/// Repository persisted to a single JSON document so vacancies survive restarts.
+///
+/// Every write rewrites the document through a temporary sibling file and an atomic
+/// rename, so a crash mid-write leaves the previous snapshot intact.
+#[derive(Debug)]
+pub struct FileVacancyRepository {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileVacancyRepository {
+    /// Opens (or lazily creates) the store at `path`; a missing file is an empty store.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, RepositoryError> {
+        let repository = Self {
+            path: path.as_ref().to_path_buf(),
+            lock: Mutex::new(()),
+        };
+        repository.load()?;
+        Ok(repository)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn load(&self) -> Result<VacancyRecords, RepositoryError> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(VacancyRecords::new())
+            }
+            Err(err) => return Err(unavailable(&self.path, err)),
+        };
+        let records: Vec<VacancyRecord> =
+            serde_json::from_str(&contents).map_err(|err| unavailable(&self.path, err))?;
+        Ok(records
+            .into_iter()
+            .map(|record| (record.id.clone(), record))
+            .collect())
+    }
+
+    fn save(&self, records: &VacancyRecords) -> Result<(), RepositoryError> {
+        let records: Vec<&VacancyRecord> = records.values().collect();
+        let contents =
+            serde_json::to_vec_pretty(&records).map_err(|err| unavailable(&self.path, err))?;
+        let staging = self.path.with_extension("json.tmp");
+        std::fs::write(&staging, contents).map_err(|err| unavailable(&staging, err))?;
+        std::fs::rename(&staging, &self.path).map_err(|err| unavailable(&self.path, err))
+    }
+
+    fn modify<F>(&self, change: F) -> Result<(), RepositoryError>
+    where
+        F: FnOnce(&mut VacancyRecords) -> Result<(), RepositoryError>,
+    {
+        let _guard = self.lock.lock().expect("repository mutex poisoned");
+        let mut records = self.load()?;
+        change(&mut records)?;
+        self.save(&records)
+    }
+}
+
+impl VacancyWorkflowRepository for FileVacancyRepository {
+    fn create(&self, record: VacancyRecord) -> Result<VacancyRecord, RepositoryError> {
+        self.modify(|records| insert_new(records, &record))?;
+        Ok(record)
+    }
+
+    fn update(&self, record: VacancyRecord) -> Result<(), RepositoryError> {
+        self.modify(|records| replace_existing(records, record))
+    }
+
+    fn fetch(&self, id: &VacancyId) -> Result<Option<VacancyRecord>, RepositoryError> {
+        let _guard = self.lock.lock().expect("repository mutex poisoned");
+        Ok(self.load()?.remove(id))
+    }
+
+    fn list_by_property(&self, property_code: &str) -> Result<Vec<VacancyRecord>, RepositoryError> {
+        let _guard = self.lock.lock().expect("repository mutex poisoned");
+        Ok(for_property(&self.load()?, property_code))
+    }
+
+    fn list_all(&self) -> Result<Vec<VacancyRecord>, RepositoryError> {
+        let _guard = self.lock.lock().expect("repository mutex poisoned");
+        Ok(self.load()?.into_values().collect())
+    }
+}
+
+fn unavailable(path: &Path, err: impl fmt::Display) -> RepositoryError {
+    RepositoryError::Unavailable(format!("{}: {}", path.display(), err))
+}
@@ -0,0 +1,151 @@
+use super::calendar::BusinessCalendar;
+use super::domain::{TaskStatus, VacancyRole};
+use super::instance::TaskInstance;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Critical-path scheduling output for a single open task.
+///
+/// Earliest dates chain forward from today (or the vacancy start) through each
+/// prerequisite; latest dates chain backward from the target move-in. Durations and
+/// `slack_days` count business days on the instance calendar; slack is how far the task
+/// can slip before move-in moves, and is negative when the remaining work already
+/// overruns the move-in date. `critical` marks the chain of binding prerequisites that
+/// ends at move-in with the least slack.
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskScheduleView {
+    pub key: String,
+    pub name: String,
+    pub role: VacancyRole,
+    pub role_label: &'static str,
+    pub duration_days: u32,
+    pub earliest_start: NaiveDate,
+    pub earliest_finish: NaiveDate,
+    pub latest_start: NaiveDate,
+    pub latest_finish: NaiveDate,
+    pub slack_days: i64,
+    pub critical: bool,
+}
+
+pub(crate) fn compute(
+    tasks: &[TaskInstance],
+    order: &[usize],
+    calendar: &BusinessCalendar,
+    vacancy_start: NaiveDate,
+    target_move_in: NaiveDate,
+    today: NaiveDate,
+) -> Vec<TaskScheduleView> {
+    let index: HashMap<&str, usize> = tasks
+        .iter()
+        .enumerate()
+        .map(|(position, task)| (task.template.key.as_str(), position))
+        .collect();
+    let predecessors: Vec<Vec<usize>> = tasks
+        .iter()
+        .map(|task| {
+            task.template
+                .prerequisites
+                .iter()
+                .filter_map(|key| index.get(key.as_str()).copied())
+                .collect()
+        })
+        .collect();
+
+    let start_floor = today.max(vacancy_start);
+    let mut earliest_start = vec![start_floor; tasks.len()];
+    let mut earliest_finish = vec![start_floor; tasks.len()];
+    for &position in order {
+        let task = &tasks[position];
+        if task.status == TaskStatus::Completed {
+            let finished = task.completed_on.unwrap_or(start_floor);
+            earliest_start[position] = finished;
+            earliest_finish[position] = finished;
+            continue;
+        }
+
+        let start = predecessors[position]
+            .iter()
+            .map(|&predecessor| earliest_finish[predecessor])
+            .fold(start_floor, NaiveDate::max);
+        earliest_start[position] = calendar.roll_forward(start);
+        earliest_finish[position] = calendar.add_business_days(start, task.template.duration_days);
+    }
+
+    let mut latest_start = vec![target_move_in; tasks.len()];
+    let mut latest_finish = vec![target_move_in; tasks.len()];
+    for &position in order.iter().rev() {
+        let task = &tasks[position];
+        if task.status == TaskStatus::Completed {
+            continue;
+        }
+
+        latest_start[position] =
+            calendar.subtract_business_days(latest_finish[position], task.template.duration_days);
+        for &predecessor in &predecessors[position] {
+            latest_finish[predecessor] = latest_finish[predecessor].min(latest_start[position]);
+        }
+    }
+
+    let open: Vec<usize> = order
+        .iter()
+        .copied()
+        .filter(|&position| tasks[position].status != TaskStatus::Completed)
+        .collect();
+    let slack = |position: usize| {
+        calendar.business_days_between(earliest_start[position], latest_start[position])
+    };
+    let minimum_slack = open.iter().map(|&position| slack(position)).min();
+
+    // Walk back from the least-slack tasks nothing open depends on, following only
+    // prerequisites that share that slack and whose finish drives the successor's start.
+    let is_open = |position: usize| tasks[position].status != TaskStatus::Completed;
+    let mut has_open_successor = vec![false; tasks.len()];
+    for &position in &open {
+        for &predecessor in &predecessors[position] {
+            has_open_successor[predecessor] = true;
+        }
+    }
+    let mut critical = vec![false; tasks.len()];
+    let mut pending: Vec<usize> = open
+        .iter()
+        .copied()
+        .filter(|&position| Some(slack(position)) == minimum_slack && !has_open_successor[position])
+        .collect();
+    while let Some(position) = pending.pop() {
+        if std::mem::replace(&mut critical[position], true) {
+            continue;
+        }
+        pending.extend(
+            predecessors[position]
+                .iter()
+                .copied()
+                .filter(|&predecessor| {
+                    is_open(predecessor)
+                        && Some(slack(predecessor)) == minimum_slack
+                        && calendar.roll_forward(earliest_finish[predecessor])
+                            == earliest_start[position]
+                }),
+        );
+    }
+
+    open.into_iter()
+        .map(|position| {
+            let task = &tasks[position];
+            let slack_days = slack(position);
+            TaskScheduleView {
+                key: task.template.key.clone(),
+                name: task.template.name.clone(),
+                role: task.template.primary_role,
+                role_label: task.template.primary_role.label(),
+                duration_days: task.template.duration_days,
+                earliest_start: earliest_start[position],
+                earliest_finish: earliest_finish[position],
+                latest_start: latest_start[position],
+                latest_finish: latest_finish[position],
+                slack_days,
+                critical: critical[position],
+            }
+        })
+        .collect()
+}
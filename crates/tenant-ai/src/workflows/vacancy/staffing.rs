@@ -0,0 +1,408 @@
+use super::domain::{TaskStatus, VacancyRole};
+use super::instance::VacancyWorkflowInstance;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::io::Read;
+use std::path::Path;
+
+/// A named person who can pick up tasks for one or more roles.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaffMember {
+    pub id: String,
+    pub name: String,
+    pub roles: Vec<VacancyRole>,
+}
+
+/// Staff available for assignment, in the order round-robin assignment visits them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaffRoster {
+    members: Vec<StaffMember>,
+}
+
+impl StaffRoster {
+    pub fn new(members: Vec<StaffMember>) -> Result<Self, RosterError> {
+        let mut seen = HashSet::with_capacity(members.len());
+        for member in &members {
+            if !seen.insert(member.id.as_str()) {
+                return Err(RosterError::DuplicateMember(member.id.clone()));
+            }
+        }
+        Ok(Self { members })
+    }
+
+    /// Loads a TOML roster made of `[[members]]` tables with `id`, `name`, and `roles`.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, RosterError> {
+        let file = std::fs::File::open(path)?;
+        Self::from_toml_reader(file)
+    }
+
+    pub fn from_toml_reader<R: Read>(mut reader: R) -> Result<Self, RosterError> {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+        let roster: StaffRoster = toml::from_str(&contents)?;
+        Self::new(roster.members)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn members(&self) -> &[StaffMember] {
+        &self.members
+    }
+
+    pub fn member(&self, id: &str) -> Option<&StaffMember> {
+        self.members.iter().find(|member| member.id == id)
+    }
+
+    pub fn members_for_role(&self, role: VacancyRole) -> impl Iterator<Item = &StaffMember> + '_ {
+        self.members
+            .iter()
+            .filter(move |member| member.roles.contains(&role))
+    }
+}
+
+#[derive(Debug)]
+pub enum RosterError {
+    Io(std::io::Error),
+    Toml(toml::de::Error),
+    DuplicateMember(String),
+}
+
+impl fmt::Display for RosterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RosterError::Io(err) => write!(f, "failed to read staff roster: {}", err),
+            RosterError::Toml(err) => write!(f, "invalid staff roster TOML: {}", err),
+            RosterError::DuplicateMember(id) => {
+                write!(f, "staff member {} is listed more than once", id)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RosterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RosterError::Io(err) => Some(err),
+            RosterError::Toml(err) => Some(err),
+            RosterError::DuplicateMember(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for RosterError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for RosterError {
+    fn from(err: toml::de::Error) -> Self {
+        Self::Toml(err)
+    }
+}
+
+/// How unassigned tasks are handed to staff holding the task's role.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssignmentPolicy {
+    /// Rotate through the role's members in roster order.
+    RoundRobin,
+    /// Give each task to the member with the fewest open tasks.
+    #[default]
+    LeastLoaded,
+}
+
+impl AssignmentPolicy {
+    pub fn from_key(value: &str) -> Option<Self> {
+        match value.trim() {
+            "round_robin" | "round-robin" => Some(Self::RoundRobin),
+            "least_loaded" | "least-loaded" => Some(Self::LeastLoaded),
+            _ => None,
+        }
+    }
+}
+
+/// Assigns open tasks across one or more vacancies, carrying load and rotation state
+/// between instances so a whole portfolio is balanced together.
+#[derive(Debug)]
+pub struct StaffAssigner<'a> {
+    roster: &'a StaffRoster,
+    policy: AssignmentPolicy,
+    open_load: HashMap<String, usize>,
+    cursors: HashMap<VacancyRole, usize>,
+}
+
+impl<'a> StaffAssigner<'a> {
+    pub fn new(roster: &'a StaffRoster, policy: AssignmentPolicy) -> Self {
+        Self {
+            roster,
+            policy,
+            open_load: HashMap::new(),
+            cursors: HashMap::new(),
+        }
+    }
+
+    /// Counts tasks already assigned in `instance` toward each member's load.
+    pub fn observe(&mut self, instance: &VacancyWorkflowInstance) {
+        for task in instance.tasks() {
+            if let Some(assignee) = &task.assignee {
+                if task.status != TaskStatus::Completed {
+                    *self.open_load.entry(assignee.clone()).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    /// Assigns every open, unassigned task whose role has at least one roster member.
+    /// Returns the `(task_key, staff_id)` pairs that were assigned.
+    pub fn assign(&mut self, instance: &mut VacancyWorkflowInstance) -> Vec<(String, String)> {
+        let pending: Vec<(String, VacancyRole)> = instance
+            .tasks_in_dependency_order()
+            .filter(|task| task.assignee.is_none() && task.status != TaskStatus::Completed)
+            .map(|task| (task.template.key.clone(), task.template.primary_role))
+            .collect();
+
+        let mut assigned = Vec::new();
+        for (task_key, role) in pending {
+            let Some(staff_id) = self.pick(role) else {
+                continue;
+            };
+            instance
+                .assign(&task_key, Some(&staff_id))
+                .expect("pending task keys come from the instance");
+            *self.open_load.entry(staff_id.clone()).or_default() += 1;
+            assigned.push((task_key, staff_id));
+        }
+        assigned
+    }
+
+    fn pick(&mut self, role: VacancyRole) -> Option<String> {
+        let candidates: Vec<&StaffMember> = self.roster.members_for_role(role).collect();
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let chosen = match self.policy {
+            AssignmentPolicy::RoundRobin => {
+                let cursor = self.cursors.entry(role).or_default();
+                let member = candidates[*cursor % candidates.len()];
+                *cursor += 1;
+                member
+            }
+            AssignmentPolicy::LeastLoaded => candidates
+                .iter()
+                .copied()
+                .min_by_key(|member| self.open_load.get(&member.id).copied().unwrap_or(0))
+                .expect("candidates is not empty"),
+        };
+        Some(chosen.id.clone())
+    }
+}
+
+/// Open and overdue work carried by one staff member.
+#[derive(Debug, Clone, Serialize)]
+pub struct StaffLoadEntry {
+    pub staff_id: String,
+    pub name: String,
+    pub roles: Vec<VacancyRole>,
+    pub open: usize,
+    pub overdue: usize,
+}
+
+/// Open work assigned to an id that is not on the roster, e.g. someone who has left.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnknownAssignee {
+    pub assignee: String,
+    pub open: usize,
+    pub overdue: usize,
+}
+
+/// Proposal to move a not-yet-started task from an overloaded member to a lighter one.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReassignmentSuggestion {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vacancy_id: Option<String>,
+    pub task_key: String,
+    pub task_name: String,
+    pub role: VacancyRole,
+    pub from_staff_id: String,
+    pub to_staff_id: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StaffingReport {
+    pub staff_load: Vec<StaffLoadEntry>,
+    /// Open tasks with no assignee, e.g. because no roster member holds their role.
+    pub unassigned_open: usize,
+    /// Open tasks assigned to ids missing from the roster, so they count toward nobody's load.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unknown_assignees: Vec<UnknownAssignee>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reassignment_suggestions: Vec<ReassignmentSuggestion>,
+}
+
+/// A member is considered overloaded once they carry this many more open tasks than
+/// the lightest member sharing the role.
+const REBALANCE_THRESHOLD: usize = 2;
+
+struct OpenAssignment<'a> {
+    vacancy_id: Option<&'a str>,
+    key: &'a str,
+    name: &'a str,
+    role: VacancyRole,
+    due_date: NaiveDate,
+    movable: bool,
+}
+
+/// Builds per-person load and rebalancing suggestions across `vacancies`, each paired
+/// with an optional vacancy id used to label suggestions.
+pub fn staffing_report<'a, I>(
+    vacancies: I,
+    roster: &StaffRoster,
+    today: NaiveDate,
+) -> StaffingReport
+where
+    I: IntoIterator<Item = (Option<&'a str>, &'a VacancyWorkflowInstance)>,
+{
+    let mut load: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    let mut by_member: HashMap<&str, Vec<OpenAssignment<'a>>> = HashMap::new();
+    let mut unassigned_open = 0;
+    let mut unknown: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+
+    for (vacancy_id, instance) in vacancies {
+        for task in instance.tasks() {
+            if task.status == TaskStatus::Completed {
+                continue;
+            }
+            let Some(assignee) = task.assignee.as_deref() else {
+                unassigned_open += 1;
+                continue;
+            };
+            let overdue = usize::from(instance.is_overdue(task, today));
+            let Some(member) = roster.member(assignee) else {
+                let entry = unknown.entry(assignee).or_default();
+                entry.0 += 1;
+                entry.1 += overdue;
+                continue;
+            };
+
+            let entry = load.entry(member.id.as_str()).or_default();
+            entry.0 += 1;
+            entry.1 += overdue;
+            by_member
+                .entry(member.id.as_str())
+                .or_default()
+                .push(OpenAssignment {
+                    vacancy_id,
+                    key: &task.template.key,
+                    name: &task.template.name,
+                    role: task.template.primary_role,
+                    due_date: task.due_date,
+                    movable: task.status == TaskStatus::NotStarted,
+                });
+        }
+    }
+
+    let staff_load = roster
+        .members()
+        .iter()
+        .map(|member| {
+            let (open, overdue) = load.get(member.id.as_str()).copied().unwrap_or_default();
+            StaffLoadEntry {
+                staff_id: member.id.clone(),
+                name: member.name.clone(),
+                roles: member.roles.clone(),
+                open,
+                overdue,
+            }
+        })
+        .collect();
+
+    StaffingReport {
+        staff_load,
+        unassigned_open,
+        unknown_assignees: unknown
+            .into_iter()
+            .map(|(assignee, (open, overdue))| UnknownAssignee {
+                assignee: assignee.to_string(),
+                open,
+                overdue,
+            })
+            .collect(),
+        reassignment_suggestions: suggest_reassignments(roster, load, by_member),
+    }
+}
+
+fn suggest_reassignments(
+    roster: &StaffRoster,
+    load: BTreeMap<&str, (usize, usize)>,
+    mut by_member: HashMap<&str, Vec<OpenAssignment<'_>>>,
+) -> Vec<ReassignmentSuggestion> {
+    let mut open: HashMap<&str, usize> =
+        load.into_iter().map(|(id, (open, _))| (id, open)).collect();
+    for assignments in by_member.values_mut() {
+        assignments.sort_by_key(|assignment| assignment.due_date);
+    }
+
+    let mut suggestions = Vec::new();
+    for role in VacancyRole::ordered() {
+        let members: Vec<&str> = roster
+            .members_for_role(role)
+            .map(|member| member.id.as_str())
+            .collect();
+        if members.len() < 2 {
+            continue;
+        }
+
+        loop {
+            let load_of = |id: &str| open.get(id).copied().unwrap_or(0);
+            let heaviest = *members
+                .iter()
+                .max_by_key(|id| load_of(id))
+                .expect("two or more members");
+            let lightest = *members
+                .iter()
+                .min_by_key(|id| load_of(id))
+                .expect("two or more members");
+            if load_of(heaviest) < load_of(lightest) + REBALANCE_THRESHOLD {
+                break;
+            }
+
+            let Some(assignments) = by_member.get_mut(heaviest) else {
+                break;
+            };
+            let Some(position) = assignments
+                .iter()
+                .position(|assignment| assignment.movable && assignment.role == role)
+            else {
+                break;
+            };
+            let assignment = assignments.remove(position);
+
+            suggestions.push(ReassignmentSuggestion {
+                vacancy_id: assignment.vacancy_id.map(str::to_string),
+                task_key: assignment.key.to_string(),
+                task_name: assignment.name.to_string(),
+                role,
+                from_staff_id: heaviest.to_string(),
+                to_staff_id: lightest.to_string(),
+                reason: format!(
+                    "{} has {} open task(s) versus {} for {}",
+                    heaviest,
+                    load_of(heaviest),
+                    load_of(lightest),
+                    lightest
+                ),
+            });
+            *open.entry(heaviest).or_default() -= 1;
+            *open.entry(lightest).or_default() += 1;
+        }
+    }
+
+    suggestions
+}
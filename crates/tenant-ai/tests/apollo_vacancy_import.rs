@@ -1,6 +1,13 @@
 use chrono::{Duration, NaiveDate};
-use tenant_ai::workflows::apollo::ApolloVacancyImporter;
-use tenant_ai::workflows::vacancy::{domain::TaskStatus, VacancyWorkflowBlueprint};
+use std::collections::BTreeMap;
+use tenant_ai::workflows::apollo::{
+    ApolloImport, ApolloImportOptions, ApolloNameMap, ApolloVacancyImportError,
+    ApolloVacancyImporter, DuplicateRow, DuplicateRowPolicy, ExcludedRow, InvalidDateRow,
+    RowConflict, UnmappedRow,
+};
+use tenant_ai::workflows::vacancy::{
+    domain::TaskStatus, BlueprintRegistry, VacancyWorkflowBlueprint,
+};
 
 fn sample_dates() -> (NaiveDate, NaiveDate) {
     let vacancy_start = NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start");
@@ -55,3 +62,596 @@ fn importer_handles_full_apollo_export() {
         TaskStatus::NotStarted | TaskStatus::InProgress | TaskStatus::Completed
     )));
 }
+
+#[test]
+fn import_reports_unmapped_duplicate_and_invalid_rows() {
+    let csv = "Task ID,Created At,Completed At,Last Modified,Name\n\
+1,2025-09-24T10:00:00Z,2025-09-25T12:15:00Z,2025-09-25T12:15:00Z,Create and Publish Listing - Leasing Agent\n\
+2,2025-09-24T11:00:00Z,,2025-09-24T18:00:00Z,Create and Publish Listing - Leasing Agent\n\
+3,2025-09-24T11:00:00Z,,2025-09-24T18:00:00Z,Marketing and Advertising\n\
+4,2025-09-24,next tuesday,2025-09-24,Collect Funds - Property Manager / Accounting\n";
+
+    let (vacancy_start, target_move_in) = sample_dates();
+    let import = ApolloVacancyImporter::import(csv.as_bytes(), vacancy_start, target_move_in)
+        .expect("import succeeds");
+    let diagnostics = &import.diagnostics;
+
+    assert_eq!(diagnostics.rows_read, 4);
+    assert_eq!(
+        diagnostics.unmapped_rows,
+        vec![UnmappedRow {
+            row: 4,
+            name: "Marketing and Advertising".to_string(),
+            suggestion: None,
+        }]
+    );
+    assert_eq!(
+        diagnostics.duplicate_rows,
+        vec![DuplicateRow {
+            row: 3,
+            name: "Create and Publish Listing - Leasing Agent".to_string(),
+            task_key: "marketing_publish_listing".to_string(),
+            applied_row: 2,
+        }]
+    );
+    assert_eq!(
+        diagnostics.invalid_dates,
+        vec![InvalidDateRow {
+            row: 5,
+            name: "Collect Funds - Property Manager / Accounting".to_string(),
+            column: "Completed At",
+            value: "next tuesday".to_string(),
+        }]
+    );
+    assert!(diagnostics.excluded_rows.is_empty());
+    assert!(!diagnostics.is_clean());
+
+    let missing: Vec<&str> = diagnostics
+        .missing_tasks
+        .iter()
+        .map(|task| task.task_key.as_str())
+        .collect();
+    assert!(!missing.contains(&"marketing_publish_listing"));
+    assert!(!missing.contains(&"leasing_collect_funds"));
+    assert!(missing.contains(&"handoff_start_new_resident_workflow"));
+    assert_eq!(
+        missing.len(),
+        import.instance.tasks().len() - 2,
+        "every other blueprint task is missing from the export"
+    );
+
+    let collect_funds = import
+        .instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "leasing_collect_funds")
+        .expect("collect funds present");
+    assert_eq!(collect_funds.status, TaskStatus::NotStarted);
+}
+
+#[test]
+fn import_reports_rows_outside_the_selected_blueprint() {
+    let csv = "Name,Created At,Completed At,Last Modified\n\
+Complete LIHTC Initial Certification - Compliance Coordinator,2025-09-24,2025-09-26,2025-09-26\n";
+    let registry = BlueprintRegistry::builtin();
+    let market_rate = registry
+        .select(Some("market_rate"))
+        .expect("market rate registered");
+
+    let (vacancy_start, target_move_in) = sample_dates();
+    let import = ApolloVacancyImporter::import_with_blueprint(
+        csv.as_bytes(),
+        market_rate,
+        vacancy_start,
+        target_move_in,
+    )
+    .expect("import succeeds");
+
+    assert_eq!(
+        import.diagnostics.excluded_rows,
+        vec![ExcludedRow {
+            row: 2,
+            name: "Complete LIHTC Initial Certification - Compliance Coordinator".to_string(),
+            task_key: "leasing_lihtc_certification".to_string(),
+        }]
+    );
+    assert!(import
+        .diagnostics
+        .missing_tasks
+        .iter()
+        .all(|task| task.task_key != "leasing_lihtc_certification"));
+}
+
+#[test]
+fn full_apollo_export_finds_every_blueprint_task() {
+    let data = include_bytes!("../Apollo_Apartments.csv");
+    let (vacancy_start, target_move_in) = sample_dates();
+
+    let import = ApolloVacancyImporter::import(&data[..], vacancy_start, target_move_in)
+        .expect("apollo dataset imports");
+
+    assert!(import.diagnostics.missing_tasks.is_empty());
+    assert!(import.diagnostics.invalid_dates.is_empty());
+    assert!(!import.diagnostics.unmapped_rows.is_empty());
+    assert!(import.diagnostics.unmapped_rows.len() < import.diagnostics.rows_read);
+}
+
+#[test]
+fn import_fuzzy_matches_misspelt_task_names() {
+    let csv = "Name,Created At,Completed At,Last Modified\n\
+Create and Publsh Listing - Leasing Agnt,2025-09-24,2025-09-25,2025-09-25\n\
+Create and Publish Listing - Leasing Agent,2025-09-24,,2025-09-25\n";
+
+    let (vacancy_start, target_move_in) = sample_dates();
+    let import = ApolloVacancyImporter::import(csv.as_bytes(), vacancy_start, target_move_in)
+        .expect("import succeeds");
+
+    let matched = &import.diagnostics.fuzzy_matches;
+    assert_eq!(matched.len(), 1, "exact names never go through the matcher");
+    assert_eq!(matched[0].row, 2);
+    assert_eq!(matched[0].task_key, "marketing_publish_listing");
+    assert!(matched[0].confidence >= ApolloImportOptions::DEFAULT_MATCH_THRESHOLD);
+    assert!(import.diagnostics.unmapped_rows.is_empty());
+
+    let listing = import
+        .instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_publish_listing")
+        .expect("listing task present");
+    assert_eq!(listing.status, TaskStatus::Completed);
+}
+
+#[test]
+fn import_suggests_weak_fuzzy_matches_below_threshold() {
+    let csv = "Name,Created At,Completed At,Last Modified\n\
+New Resident Workflow,2025-09-24,2025-09-30,2025-09-30\n";
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = sample_dates();
+
+    let strict = ApolloVacancyImporter::import(csv.as_bytes(), vacancy_start, target_move_in)
+        .expect("import succeeds");
+    let unmapped = &strict.diagnostics.unmapped_rows;
+    assert_eq!(unmapped.len(), 1);
+    let suggestion = unmapped[0]
+        .suggestion
+        .as_ref()
+        .expect("close task suggested");
+    assert_eq!(suggestion.task_key, "handoff_start_new_resident_workflow");
+    assert!(suggestion.confidence < ApolloImportOptions::DEFAULT_MATCH_THRESHOLD);
+    assert!(strict
+        .instance
+        .tasks()
+        .iter()
+        .all(|task| task.status == TaskStatus::NotStarted));
+
+    let lenient = ApolloVacancyImporter::import_with_options(
+        csv.as_bytes(),
+        &blueprint,
+        vacancy_start,
+        target_move_in,
+        &ApolloImportOptions {
+            match_threshold: suggestion.confidence,
+            ..ApolloImportOptions::default()
+        },
+    )
+    .expect("import succeeds");
+    assert!(lenient.diagnostics.unmapped_rows.is_empty());
+    assert_eq!(
+        lenient.diagnostics.fuzzy_matches[0].task_key,
+        "handoff_start_new_resident_workflow"
+    );
+}
+
+#[test]
+fn name_map_loads_csv_and_toml_entries() {
+    let from_csv = ApolloNameMap::from_csv_reader(
+        "name,task_key\nList unit on Zillow,marketing_publish_listing\nFinal walkthrough , leasing_conduct_move_in_inspection\n"
+            .as_bytes(),
+    )
+    .expect("csv map parses");
+    let from_toml = ApolloNameMap::from_toml_str(
+        "\"List unit on Zillow\" = \"marketing_publish_listing\"\n\"Final walkthrough\" = \"leasing_conduct_move_in_inspection\"\n",
+    )
+    .expect("toml map parses");
+
+    assert_eq!(from_csv, from_toml);
+    assert_eq!(from_csv.len(), 2);
+    assert!(from_csv
+        .entries()
+        .any(|entry| entry == ("Final walkthrough", "leasing_conduct_move_in_inspection")));
+
+    let error = ApolloNameMap::from_path("names.json").expect_err("json is not supported");
+    assert!(matches!(
+        error,
+        ApolloVacancyImportError::UnsupportedNameMapFormat(_)
+    ));
+}
+
+#[test]
+fn name_map_entries_win_over_the_built_in_map() {
+    let csv = "Name,Created At,Completed At,Last Modified\n\
+List unit on Zillow,2025-09-24,2025-09-25,2025-09-25\n\
+Update Vacancy in AppFolio - Leasing Agent,2025-09-24,2025-09-26,2025-09-26\n";
+    let name_map: ApolloNameMap = [
+        ("List unit on Zillow", "marketing_publish_listing"),
+        (
+            "Update Vacancy in AppFolio - Leasing Agent",
+            "screening_manage_inquiries",
+        ),
+    ]
+    .into_iter()
+    .collect();
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = sample_dates();
+
+    let import = ApolloVacancyImporter::import_with_options(
+        csv.as_bytes(),
+        &blueprint,
+        vacancy_start,
+        target_move_in,
+        &ApolloImportOptions {
+            name_map,
+            ..ApolloImportOptions::default()
+        },
+    )
+    .expect("import succeeds");
+
+    let status_of = |key: &str| {
+        import
+            .instance
+            .tasks()
+            .iter()
+            .find(|task| task.template.key == key)
+            .map(|task| task.status)
+            .expect("task present")
+    };
+    assert_eq!(
+        status_of("marketing_publish_listing"),
+        TaskStatus::Completed
+    );
+    assert_eq!(
+        status_of("screening_manage_inquiries"),
+        TaskStatus::Completed
+    );
+    assert_eq!(
+        status_of("marketing_update_appfolio"),
+        TaskStatus::NotStarted
+    );
+    assert!(import.diagnostics.unmapped_rows.is_empty());
+    assert!(import.diagnostics.fuzzy_matches.is_empty());
+}
+
+#[test]
+fn name_map_rejects_tasks_outside_the_active_blueprint() {
+    let registry = BlueprintRegistry::builtin();
+    let market_rate = registry
+        .select(Some("market_rate"))
+        .expect("market rate registered");
+    let name_map: ApolloNameMap = [
+        ("Finalize TIC", "leasing_lihtc_certification"),
+        ("List unit on Zillow", "marketing_publish_listing"),
+    ]
+    .into_iter()
+    .collect();
+    let (vacancy_start, target_move_in) = sample_dates();
+
+    let error = ApolloVacancyImporter::import_with_options(
+        "Name,Created At,Completed At,Last Modified\n".as_bytes(),
+        market_rate,
+        vacancy_start,
+        target_move_in,
+        &ApolloImportOptions {
+            name_map,
+            ..ApolloImportOptions::default()
+        },
+    )
+    .expect_err("lihtc task is not part of market rate");
+
+    match error {
+        ApolloVacancyImportError::UnknownNameMapTasks(entries) => assert_eq!(
+            entries,
+            vec![(
+                "Finalize TIC".to_string(),
+                "leasing_lihtc_certification".to_string()
+            )]
+        ),
+        other => panic!("expected unknown task error, got {other:?}"),
+    }
+}
+
+#[test]
+fn configured_name_map_excludes_rows_for_tasks_outside_the_blueprint() {
+    let registry = BlueprintRegistry::builtin();
+    let market_rate = registry
+        .select(Some("market_rate"))
+        .expect("market rate registered");
+    let configured_name_map: ApolloNameMap = [
+        ("Finalize TIC", "leasing_lihtc_certification"),
+        ("List unit on Zillow", "marketing_publish_listing"),
+    ]
+    .into_iter()
+    .collect();
+    let (vacancy_start, target_move_in) = sample_dates();
+
+    let import = ApolloVacancyImporter::import_with_options(
+        "Name,Created At,Completed At,Last Modified
+\
+Finalize TIC,2025-09-24,2025-09-25,2025-09-25
+\
+List unit on Zillow,2025-09-24,2025-09-25,2025-09-25
+"
+        .as_bytes(),
+        market_rate,
+        vacancy_start,
+        target_move_in,
+        &ApolloImportOptions {
+            configured_name_map,
+            ..ApolloImportOptions::default()
+        },
+    )
+    .expect("configured entries for other programs do not fail the import");
+
+    assert_eq!(import.diagnostics.excluded_rows.len(), 1);
+    assert_eq!(
+        import.diagnostics.excluded_rows[0].task_key,
+        "leasing_lihtc_certification"
+    );
+    let publish = import
+        .instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_publish_listing")
+        .expect("publish listing task present");
+    assert_eq!(publish.status, TaskStatus::Completed);
+}
+
+/// A listing task reopened after completion (row 2) listed before the original completed
+/// row (row 3), plus two agreeing rows for the AppFolio update (rows 4 and 5).
+const REOPENED_TASK_CSV: &str = "Name,Created At,Completed At,Last Modified\n\
+Create and Publish Listing - Leasing Agent,2025-09-26T09:00:00Z,,2025-09-27T10:00:00Z\n\
+Create and Publish Listing - Leasing Agent,2025-09-24T10:00:00Z,2025-09-25T12:00:00Z,2025-09-25T12:00:00Z\n\
+Update Vacancy in AppFolio - Leasing Agent,2025-09-24T10:00:00Z,2025-09-25T09:00:00Z,2025-09-25T09:00:00Z\n\
+Update Vacancy in AppFolio - Leasing Agent,2025-09-24T10:00:00Z,2025-09-25T15:00:00Z,2025-09-26T08:00:00Z\n";
+
+fn import_reopened(duplicate_policy: DuplicateRowPolicy) -> ApolloImport {
+    let (vacancy_start, target_move_in) = sample_dates();
+    ApolloVacancyImporter::import_with_options(
+        REOPENED_TASK_CSV.as_bytes(),
+        &VacancyWorkflowBlueprint::standard(),
+        vacancy_start,
+        target_move_in,
+        &ApolloImportOptions {
+            duplicate_policy,
+            ..ApolloImportOptions::default()
+        },
+    )
+    .expect("import succeeds")
+}
+
+fn status_of(import: &ApolloImport, key: &str) -> TaskStatus {
+    import
+        .instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == key)
+        .map(|task| task.status)
+        .expect("task present")
+}
+
+fn duplicate(row: usize, name: &str, task_key: &str, applied_row: usize) -> DuplicateRow {
+    DuplicateRow {
+        row,
+        name: name.to_string(),
+        task_key: task_key.to_string(),
+        applied_row,
+    }
+}
+
+#[test]
+fn latest_modified_policy_applies_the_newest_row_regardless_of_order() {
+    let import = import_reopened(DuplicateRowPolicy::LatestModified);
+
+    assert_eq!(
+        status_of(&import, "marketing_publish_listing"),
+        TaskStatus::InProgress
+    );
+    assert_eq!(
+        status_of(&import, "marketing_update_appfolio"),
+        TaskStatus::Completed
+    );
+    assert_eq!(
+        import.diagnostics.duplicate_rows,
+        vec![
+            duplicate(
+                3,
+                "Create and Publish Listing - Leasing Agent",
+                "marketing_publish_listing",
+                2
+            ),
+            duplicate(
+                4,
+                "Update Vacancy in AppFolio - Leasing Agent",
+                "marketing_update_appfolio",
+                5
+            ),
+        ]
+    );
+    assert!(import.diagnostics.conflicting_rows.is_empty());
+}
+
+#[test]
+fn prefer_completed_policy_keeps_a_completion_over_newer_progress() {
+    let import = import_reopened(DuplicateRowPolicy::PreferCompleted);
+
+    let listing = import
+        .instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_publish_listing")
+        .expect("listing task present");
+    assert_eq!(listing.status, TaskStatus::Completed);
+    assert_eq!(listing.completed_on, NaiveDate::from_ymd_opt(2025, 9, 25));
+    assert_eq!(
+        import.diagnostics.duplicate_rows[0],
+        duplicate(
+            2,
+            "Create and Publish Listing - Leasing Agent",
+            "marketing_publish_listing",
+            3
+        )
+    );
+}
+
+#[test]
+fn latest_modified_policy_skips_newer_rows_without_progress() {
+    let csv = "Name,Created At,Completed At,Last Modified\n\
+Create and Publish Listing - Leasing Agent,2025-09-24T10:00:00Z,2025-09-25T12:00:00Z,2025-09-25T12:00:00Z\n\
+Create and Publish Listing - Leasing Agent,2025-09-26T09:00:00Z,,2025-09-26T09:00:00Z\n";
+    let (vacancy_start, target_move_in) = sample_dates();
+
+    let import = ApolloVacancyImporter::import(csv.as_bytes(), vacancy_start, target_move_in)
+        .expect("import succeeds");
+
+    assert_eq!(
+        status_of(&import, "marketing_publish_listing"),
+        TaskStatus::Completed
+    );
+    assert_eq!(
+        import.diagnostics.duplicate_rows,
+        vec![duplicate(
+            3,
+            "Create and Publish Listing - Leasing Agent",
+            "marketing_publish_listing",
+            2
+        )]
+    );
+}
+
+#[test]
+fn report_conflicts_policy_leaves_disagreeing_tasks_unchanged() {
+    let import = import_reopened(DuplicateRowPolicy::ReportConflicts);
+
+    assert_eq!(
+        status_of(&import, "marketing_publish_listing"),
+        TaskStatus::NotStarted
+    );
+    assert_eq!(
+        status_of(&import, "marketing_update_appfolio"),
+        TaskStatus::Completed
+    );
+    assert_eq!(
+        import.diagnostics.conflicting_rows,
+        vec![RowConflict {
+            task_key: "marketing_publish_listing".to_string(),
+            rows: vec![2, 3],
+        }]
+    );
+    assert_eq!(import.diagnostics.duplicate_rows.len(), 1);
+    assert!(!import.diagnostics.is_clean());
+}
+
+#[test]
+fn asana_json_export_imports_like_the_csv() {
+    let data = include_bytes!("../Apollo_Apartments_asana.json");
+    let (vacancy_start, target_move_in) = sample_dates();
+
+    let import = ApolloVacancyImporter::import(&data[..], vacancy_start, target_move_in)
+        .expect("asana export imports");
+
+    let listing = import
+        .instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_publish_listing")
+        .expect("listing task present");
+    assert_eq!(listing.status, TaskStatus::Completed);
+    assert_eq!(listing.completed_on, NaiveDate::from_ymd_opt(2025, 9, 25));
+    assert_eq!(
+        status_of(&import, "marketing_update_appfolio"),
+        TaskStatus::InProgress
+    );
+    assert_eq!(
+        status_of(&import, "screening_manage_inquiries"),
+        TaskStatus::InProgress,
+        "a reopened task ignores its stale completed_at"
+    );
+    assert_eq!(import.diagnostics.rows_read, 4);
+    assert_eq!(
+        import.diagnostics.unmapped_rows,
+        vec![UnmappedRow {
+            row: 4,
+            name: "Marketing and Advertising".to_string(),
+            suggestion: None,
+        }]
+    );
+    assert!(import.diagnostics.invalid_dates.is_empty());
+}
+
+#[test]
+fn asana_records_carry_assignee_due_date_sections_and_custom_fields() {
+    let data = include_bytes!("../Apollo_Apartments_asana.json");
+
+    let records = ApolloVacancyImporter::records(&data[..]).expect("asana export parses");
+
+    assert_eq!(records.len(), 4);
+    let listing = &records[0];
+    assert_eq!(listing.row, 1);
+    assert_eq!(listing.assignee.as_deref(), Some("Ana Leasing"));
+    assert_eq!(listing.due_on, NaiveDate::from_ymd_opt(2025, 9, 24));
+    assert_eq!(listing.sections, vec!["Marketing and Advertising"]);
+    assert_eq!(
+        listing.custom_fields,
+        BTreeMap::from([
+            ("Priority".to_string(), "High".to_string()),
+            ("Unit".to_string(), "204".to_string()),
+        ])
+    );
+    assert_eq!(records[2].assignee, None);
+    assert_eq!(records[2].completed_on, None);
+
+    let csv = "Task ID,Created At,Completed At,Last Modified,Name,Section/Column,Assignee,Due Date\n\
+1,2025-09-24,,2025-09-25,Create and Publish Listing - Leasing Agent,Marketing and Advertising,Ana Leasing,2025-09-24\n";
+    let csv_records = ApolloVacancyImporter::records(csv.as_bytes()).expect("csv parses");
+    assert_eq!(csv_records[0].assignee, listing.assignee);
+    assert_eq!(csv_records[0].due_on, listing.due_on);
+    assert_eq!(csv_records[0].sections, listing.sections);
+    assert!(csv_records[0].custom_fields.is_empty());
+}
+
+#[test]
+fn asana_api_payloads_accept_single_tasks_and_bare_arrays() {
+    let (vacancy_start, target_move_in) = sample_dates();
+    let single = r#"{"data": {"gid": "1208704778510660", "name": "Create and Publish Listing - Leasing Agent", "completed": true, "completed_at": "2025-09-25T12:15:00.000Z", "created_at": "2025-09-24T10:00:00.000Z", "modified_at": "2025-09-25T12:15:00.000Z"}}"#;
+    let import = ApolloVacancyImporter::import(single.as_bytes(), vacancy_start, target_move_in)
+        .expect("single task payload imports");
+    assert_eq!(
+        status_of(&import, "marketing_publish_listing"),
+        TaskStatus::Completed
+    );
+
+    let bare = r#"
+    [{"gid": "1208704778510659", "name": "Update Vacancy in AppFolio - Leasing Agent", "assignee": {"gid": "1201120553012001"}, "due_on": "someday"}]"#;
+    let records = ApolloVacancyImporter::records(bare.as_bytes()).expect("bare array parses");
+    assert_eq!(records[0].assignee, None);
+    assert_eq!(records[0].due_on, None);
+    let import = ApolloVacancyImporter::import(bare.as_bytes(), vacancy_start, target_move_in)
+        .expect("bare array imports");
+    assert_eq!(
+        import.diagnostics.invalid_dates,
+        vec![InvalidDateRow {
+            row: 1,
+            name: "Update Vacancy in AppFolio - Leasing Agent".to_string(),
+            column: "due_on",
+            value: "someday".to_string(),
+        }]
+    );
+
+    let error = ApolloVacancyImporter::import(
+        r#"{"errors": [{"message": "Not Authorized"}]}"#.as_bytes(),
+        vacancy_start,
+        target_move_in,
+    )
+    .expect_err("error payload is not an export");
+    assert!(matches!(error, ApolloVacancyImportError::Json(_)));
+}
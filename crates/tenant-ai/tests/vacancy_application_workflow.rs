@@ -17,6 +17,7 @@ mod common {
     };
     use tenant_ai::workflows::vacancy::applications::repository::{
         AlertError, AlertPublisher, AppFolioAlert, ApplicationRepository, RepositoryError,
+        VacancyAlert,
     };
     use tenant_ai::workflows::vacancy::applications::{
         ApplicationRecord, EvaluationConfig, VacancyApplicationService,
@@ -165,6 +166,10 @@ mod common {
             self.events.lock().expect("lock").push(alert);
             Ok(())
         }
+
+        fn publish_vacancy(&self, _alert: VacancyAlert) -> Result<(), AlertError> {
+            Ok(())
+        }
     }
 
     pub(super) fn build_service() -> (
@@ -0,0 +1,314 @@
+use chrono::{Duration, NaiveDate};
+use std::sync::{Arc, Mutex};
+use tenant_ai::workflows::vacancy::{
+    applications::{AlertError, AlertPublisher, AppFolioAlert},
+    domain::{TaskStatus, VacancyRole},
+    AutomationDispatcher, AutomationTrigger, VacancyAlert, VacancyWorkflowBlueprint,
+    VacancyWorkflowInstance,
+};
+
+#[derive(Default)]
+struct RecordingPublisher {
+    alerts: Mutex<Vec<VacancyAlert>>,
+    fail: bool,
+}
+
+impl AlertPublisher for RecordingPublisher {
+    fn publish(&self, _alert: AppFolioAlert) -> Result<(), AlertError> {
+        unreachable!("vacancy automation never raises application alerts")
+    }
+
+    fn publish_vacancy(&self, alert: VacancyAlert) -> Result<(), AlertError> {
+        if self.fail {
+            return Err(AlertError::Transport("queue offline".to_string()));
+        }
+        self.alerts.lock().unwrap().push(alert);
+        Ok(())
+    }
+}
+
+fn vacancy_start() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start date")
+}
+
+fn instance() -> VacancyWorkflowInstance {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    VacancyWorkflowInstance::new(
+        &blueprint,
+        vacancy_start(),
+        vacancy_start() + Duration::days(14),
+    )
+}
+
+#[test]
+fn triggers_name_tasks_and_assigned_recipients() {
+    let mut instance = instance();
+    instance
+        .assign("marketing_publish_listing", Some("ana"))
+        .expect("task exists");
+    let today = vacancy_start() + Duration::days(3);
+
+    let triggers = instance.automation_triggers(today);
+    let leasing = triggers
+        .iter()
+        .find(|trigger| {
+            matches!(
+                trigger,
+                AutomationTrigger::RemindRole {
+                    role: VacancyRole::LeasingAgent,
+                    ..
+                }
+            )
+        })
+        .expect("leasing agents have open work");
+    assert!(leasing
+        .task_keys()
+        .contains(&"marketing_publish_listing".to_string()));
+    assert_eq!(leasing.recipients(), ["ana".to_string()]);
+
+    let overdue = triggers
+        .iter()
+        .find(|trigger| matches!(trigger, AutomationTrigger::DispatchAppFolioAlert { .. }))
+        .expect("overdue work is dispatched");
+    let summary = instance.report(today).summary();
+    assert_eq!(overdue.task_keys().len(), summary.overdue_tasks.len());
+
+    let insights = summary.insights(
+        &instance,
+        instance.vacancy_start(),
+        instance.target_move_in(),
+        today,
+    );
+    assert_eq!(insights.automation_triggers, triggers);
+}
+
+#[test]
+fn reminders_skip_tasks_waiting_on_prerequisites() {
+    let instance = instance();
+    let triggers = instance.automation_triggers(vacancy_start());
+    let reminded: Vec<&String> = triggers
+        .iter()
+        .filter(|trigger| matches!(trigger, AutomationTrigger::RemindRole { .. }))
+        .flat_map(|trigger| trigger.task_keys())
+        .collect();
+    assert!(!reminded.is_empty());
+
+    let mut waiting = 0;
+    for task in instance.tasks() {
+        let blocked = !instance.pending_prerequisites(task).is_empty();
+        waiting += usize::from(blocked);
+        assert_eq!(
+            reminded.contains(&&task.template.key),
+            !blocked,
+            "{}",
+            task.template.key
+        );
+    }
+    assert!(waiting > 0, "the standard blueprint has dependent tasks");
+}
+
+#[test]
+fn completed_vacancy_has_no_reminders() {
+    let mut instance = instance();
+    while let Some(key) = instance
+        .report(vacancy_start())
+        .actionable_tasks
+        .first()
+        .map(|task| task.key.clone())
+    {
+        instance
+            .set_status(&key, TaskStatus::Completed, Some(vacancy_start()))
+            .expect("actionable tasks can complete");
+    }
+    assert!(instance
+        .automation_triggers(vacancy_start() + Duration::days(3))
+        .iter()
+        .all(|trigger| !matches!(trigger, AutomationTrigger::RemindRole { .. })));
+}
+
+#[test]
+fn dispatcher_sends_each_trigger_once_per_day() {
+    let publisher = Arc::new(RecordingPublisher::default());
+    let dispatcher = AutomationDispatcher::new(publisher.clone());
+    let instance = instance();
+    let today = vacancy_start() + Duration::days(3);
+    let triggers = instance.automation_triggers(today);
+
+    let first = dispatcher
+        .dispatch("OAK:101", &triggers, today)
+        .expect("alerts publish");
+    assert_eq!(first.sent.len(), triggers.len());
+    assert!(first.suppressed.is_empty());
+
+    let repeat = dispatcher
+        .dispatch("OAK:101", &triggers, today)
+        .expect("alerts publish");
+    assert!(repeat.sent.is_empty());
+    assert_eq!(repeat.suppressed.len(), triggers.len());
+
+    let other_unit = dispatcher
+        .dispatch("OAK:102", &triggers, today)
+        .expect("alerts publish");
+    assert_eq!(other_unit.sent.len(), triggers.len());
+
+    let next_day = dispatcher
+        .dispatch("OAK:101", &triggers, today + Duration::days(1))
+        .expect("alerts publish");
+    assert_eq!(next_day.sent.len(), triggers.len());
+
+    let alerts = publisher.alerts.lock().unwrap();
+    assert_eq!(alerts.len(), triggers.len() * 3);
+    let reminder = &alerts[0];
+    assert_eq!(reminder.template, "vacancy_task_reminder");
+    assert_eq!(reminder.vacancy_id, "OAK:101");
+    assert_eq!(
+        reminder.details.get("role").map(String::as_str),
+        Some("Leasing Agent")
+    );
+}
+
+#[test]
+fn dispatcher_ignores_task_list_changes_within_the_day() {
+    let publisher = Arc::new(RecordingPublisher::default());
+    let dispatcher = AutomationDispatcher::new(publisher.clone());
+    let mut instance = instance();
+    let today = vacancy_start() + Duration::days(3);
+    let morning = instance.automation_triggers(today);
+    dispatcher
+        .dispatch("OAK:101", &morning, today)
+        .expect("alerts publish");
+
+    let leaf = morning
+        .iter()
+        .filter(|trigger| matches!(trigger, AutomationTrigger::RemindRole { .. }))
+        .flat_map(|trigger| trigger.task_keys())
+        .find(|key| {
+            instance
+                .tasks()
+                .iter()
+                .all(|task| !task.template.prerequisites.contains(key))
+        })
+        .cloned()
+        .expect("a reminded task unblocks nothing");
+    instance
+        .set_status(&leaf, TaskStatus::Completed, Some(today))
+        .expect("reminded task can complete");
+    let afternoon = instance.automation_triggers(today);
+    assert_ne!(afternoon, morning);
+
+    let repeat = dispatcher
+        .dispatch("OAK:101", &afternoon, today)
+        .expect("alerts publish");
+    assert!(repeat.sent.is_empty());
+    assert_eq!(publisher.alerts.lock().unwrap().len(), morning.len());
+}
+
+#[test]
+fn dispatcher_ignores_recipient_changes_within_the_day() {
+    let publisher = Arc::new(RecordingPublisher::default());
+    let dispatcher = AutomationDispatcher::new(publisher.clone());
+    let mut instance = instance();
+    instance
+        .assign("marketing_publish_listing", Some("ana"))
+        .expect("task exists");
+    let today = vacancy_start() + Duration::days(3);
+    let morning = instance.automation_triggers(today);
+    dispatcher
+        .dispatch("OAK:101", &morning, today)
+        .expect("alerts publish");
+
+    instance
+        .assign("marketing_publish_listing", Some("ben"))
+        .expect("task exists");
+    let afternoon = instance.automation_triggers(today);
+    let leasing_recipients = |triggers: &[AutomationTrigger]| {
+        triggers
+            .iter()
+            .find(|trigger| {
+                matches!(
+                    trigger,
+                    AutomationTrigger::RemindRole {
+                        role: VacancyRole::LeasingAgent,
+                        ..
+                    }
+                )
+            })
+            .map(|trigger| trigger.recipients().to_vec())
+    };
+    assert_ne!(leasing_recipients(&afternoon), leasing_recipients(&morning));
+
+    let repeat = dispatcher
+        .dispatch("OAK:101", &afternoon, today)
+        .expect("alerts publish");
+    assert!(repeat.sent.is_empty());
+    assert_eq!(repeat.suppressed.len(), afternoon.len());
+    assert_eq!(publisher.alerts.lock().unwrap().len(), morning.len());
+}
+
+#[test]
+fn dispatcher_sends_newly_overdue_tasks_within_the_day() {
+    let publisher = Arc::new(RecordingPublisher::default());
+    let dispatcher = AutomationDispatcher::new(publisher.clone());
+    let mut instance = instance();
+    let today = vacancy_start() + Duration::days(3);
+    let reopened = instance
+        .report(today)
+        .summary()
+        .overdue_tasks
+        .iter()
+        .map(|task| task.key.clone())
+        .find(|key| {
+            instance
+                .set_status(key, TaskStatus::Completed, Some(today))
+                .is_ok()
+        })
+        .expect("an overdue task can complete");
+    let morning = instance.automation_triggers(today);
+    dispatcher
+        .dispatch("OAK:101", &morning, today)
+        .expect("alerts publish");
+
+    instance
+        .set_status(&reopened, TaskStatus::NotStarted, None)
+        .expect("task reopens");
+    let afternoon = instance.automation_triggers(today);
+    let repeat = dispatcher
+        .dispatch("OAK:101", &afternoon, today)
+        .expect("alerts publish");
+
+    let overdue = repeat
+        .sent
+        .iter()
+        .find(|trigger| matches!(trigger, AutomationTrigger::DispatchAppFolioAlert { .. }))
+        .expect("the reopened task is dispatched");
+    assert_eq!(overdue.task_keys(), std::slice::from_ref(&reopened));
+    let reminder = repeat
+        .sent
+        .iter()
+        .find(|trigger| matches!(trigger, AutomationTrigger::RemindRole { .. }))
+        .expect("the reopened task is reminded");
+    assert_eq!(reminder.task_keys(), std::slice::from_ref(&reopened));
+    assert_eq!(repeat.sent.len() + repeat.suppressed.len(), afternoon.len());
+
+    let alerts = publisher.alerts.lock().unwrap();
+    let last = alerts.last().expect("alerts recorded");
+    assert_eq!(
+        last.details.get("task_keys").map(String::as_str),
+        Some(reopened.as_str())
+    );
+}
+
+#[test]
+fn failed_publish_is_not_recorded_as_sent() {
+    let failing = Arc::new(RecordingPublisher {
+        fail: true,
+        ..RecordingPublisher::default()
+    });
+    let dispatcher = AutomationDispatcher::new(failing);
+    let instance = instance();
+    let today = vacancy_start() + Duration::days(3);
+    let triggers = instance.automation_triggers(today);
+
+    assert!(dispatcher.dispatch("OAK:101", &triggers, today).is_err());
+    assert!(dispatcher.dispatch("OAK:101", &triggers, today).is_err());
+}
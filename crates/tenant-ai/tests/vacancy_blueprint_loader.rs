@@ -0,0 +1,324 @@
+use std::io::Cursor;
+use tenant_ai::workflows::vacancy::{
+    domain::{DueDateRule, VacancyError, VacancyRole, VacancyStage},
+    BlueprintError, BlueprintFormat, BlueprintRegistry, VacancyWorkflowBlueprint,
+};
+
+const YAML_BLUEPRINT: &str = r#"
+tasks:
+  - key: marketing_publish_listing
+    name: Create and Publish Listing
+    stage: marketing_and_advertising
+    role: leasing_agent
+    due:
+      days_from_vacancy: 1
+    deliverables:
+      - Publish the listing to every syndication partner.
+      - name: Boost the listing on social media.
+        required: false
+    compliance:
+      - topic: Reasonable re-rental efforts
+        detail: Keep a record of each marketing channel touch.
+  - key: leasing_conduct_move_in_inspection
+    name: Conduct Move-In Inspection
+    stage: lease_signing_and_move_in
+    role: property_manager
+    due: on_move_in
+"#;
+
+#[test]
+fn standard_blueprint_loads_bundled_definition() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    assert_eq!(blueprint.task_templates().len(), 10);
+
+    let collect_funds = blueprint
+        .task_templates()
+        .iter()
+        .find(|task| task.key == "leasing_collect_funds")
+        .expect("collect funds task present");
+    assert_eq!(
+        collect_funds.primary_role,
+        VacancyRole::PropertyManagerAccounting
+    );
+    assert_eq!(collect_funds.due, DueDateRule::DaysBeforeMoveIn(5));
+}
+
+#[test]
+fn yaml_blueprint_parses_tasks_and_defaults() {
+    let blueprint =
+        VacancyWorkflowBlueprint::from_reader(Cursor::new(YAML_BLUEPRINT), BlueprintFormat::Yaml)
+            .expect("yaml blueprint loads");
+
+    let tasks = blueprint.task_templates();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].stage, VacancyStage::MarketingAndAdvertising);
+    assert_eq!(tasks[0].due, DueDateRule::DaysFromVacancy(1));
+    assert_eq!(tasks[0].compliance[0].topic, "Reasonable re-rental efforts");
+    assert_eq!(
+        tasks[0]
+            .deliverables
+            .iter()
+            .map(|deliverable| deliverable.required)
+            .collect::<Vec<_>>(),
+        vec![true, false]
+    );
+    assert_eq!(tasks[1].due, DueDateRule::OnMoveIn);
+    assert!(tasks[1].deliverables.is_empty());
+    assert!(tasks[1].compliance.is_empty());
+}
+
+#[test]
+fn toml_blueprint_rejects_duplicate_keys() {
+    let toml = r#"
+[[tasks]]
+key = "marketing_publish_listing"
+name = "Create and Publish Listing"
+stage = "marketing_and_advertising"
+role = "leasing_agent"
+due = { days_from_vacancy = 0 }
+
+[[tasks]]
+key = "marketing_publish_listing"
+name = "Publish Listing Again"
+stage = "marketing_and_advertising"
+role = "leasing_agent"
+due = "on_move_in"
+"#;
+
+    let error = VacancyWorkflowBlueprint::from_reader(Cursor::new(toml), BlueprintFormat::Toml)
+        .expect_err("duplicate keys rejected");
+    match error {
+        BlueprintError::DuplicateTaskKey(key) => assert_eq!(key, "marketing_publish_listing"),
+        other => panic!("expected duplicate key error, got {other:?}"),
+    }
+}
+
+#[test]
+fn blueprint_rejects_unknown_stage_and_role() {
+    let unknown_stage = YAML_BLUEPRINT.replace("lease_signing_and_move_in", "closing");
+    let error =
+        VacancyWorkflowBlueprint::from_reader(Cursor::new(unknown_stage), BlueprintFormat::Yaml)
+            .expect_err("unknown stage rejected");
+    match error {
+        BlueprintError::UnknownStage { task_key, stage } => {
+            assert_eq!(task_key, "leasing_conduct_move_in_inspection");
+            assert_eq!(stage, "closing");
+        }
+        other => panic!("expected unknown stage error, got {other:?}"),
+    }
+
+    let unknown_role = YAML_BLUEPRINT.replace("role: property_manager", "role: janitor");
+    let error =
+        VacancyWorkflowBlueprint::from_reader(Cursor::new(unknown_role), BlueprintFormat::Yaml)
+            .expect_err("unknown role rejected");
+    assert!(matches!(error, BlueprintError::UnknownRole { ref role, .. } if role == "janitor"));
+}
+
+#[test]
+fn blueprint_rejects_unknown_prerequisites_and_cycles() {
+    let unknown_prerequisite =
+        format!("{YAML_BLUEPRINT}    prerequisites:\n      - leasing_prepare_agreement\n");
+    let error = VacancyWorkflowBlueprint::from_reader(
+        Cursor::new(unknown_prerequisite),
+        BlueprintFormat::Yaml,
+    )
+    .expect_err("unknown prerequisite rejected");
+    assert!(matches!(
+        error,
+        BlueprintError::UnknownPrerequisite { ref prerequisite, .. }
+            if prerequisite == "leasing_prepare_agreement"
+    ));
+
+    let toml = r#"
+[[tasks]]
+key = "leasing_prepare_agreement"
+name = "Prepare Lease Agreement"
+stage = "lease_signing_and_move_in"
+role = "leasing_agent"
+due = { days_from_vacancy = 5 }
+prerequisites = ["leasing_collect_funds"]
+
+[[tasks]]
+key = "leasing_collect_funds"
+name = "Collect Move-In Funds"
+stage = "lease_signing_and_move_in"
+role = "property_manager_accounting"
+due = { days_before_move_in = 5 }
+prerequisites = ["leasing_prepare_agreement"]
+"#;
+    let error = VacancyWorkflowBlueprint::from_reader(Cursor::new(toml), BlueprintFormat::Toml)
+        .expect_err("cycle rejected");
+    match error {
+        BlueprintError::DependencyCycle(keys) => assert_eq!(
+            keys,
+            vec![
+                "leasing_prepare_agreement",
+                "leasing_collect_funds",
+                "leasing_prepare_agreement"
+            ]
+        ),
+        other => panic!("expected dependency cycle, got {other:?}"),
+    }
+}
+
+#[test]
+fn from_path_requires_known_extension() {
+    let error = VacancyWorkflowBlueprint::from_path("./blueprint.json")
+        .expect_err("json extension unsupported");
+    assert!(matches!(error, BlueprintError::UnsupportedFormat(_)));
+}
+
+fn task_keys(blueprint: &VacancyWorkflowBlueprint) -> Vec<&str> {
+    blueprint
+        .task_templates()
+        .iter()
+        .map(|task| task.key.as_str())
+        .collect()
+}
+
+#[test]
+fn builtin_registry_is_parsed_once_and_copied_for_extension() {
+    assert!(std::ptr::eq(
+        BlueprintRegistry::shared_builtin(),
+        BlueprintRegistry::shared_builtin()
+    ));
+
+    let mut registry = BlueprintRegistry::builtin();
+    registry.register("custom", VacancyWorkflowBlueprint::standard());
+    assert!(registry.get("custom").is_some());
+    assert!(BlueprintRegistry::shared_builtin().get("custom").is_none());
+}
+
+#[test]
+fn builtin_registry_exposes_program_blueprints() {
+    let registry = BlueprintRegistry::builtin();
+    assert_eq!(
+        registry.names().collect::<Vec<_>>(),
+        vec!["lihtc", "market_rate", "section_8", "student_housing"]
+    );
+    assert_eq!(
+        task_keys(registry.get("standard").expect("standard alias")),
+        task_keys(&VacancyWorkflowBlueprint::standard())
+    );
+
+    let market_rate = registry.get("market_rate").expect("market rate registered");
+    assert!(!task_keys(market_rate).contains(&"leasing_lihtc_certification"));
+    assert!(market_rate
+        .task_templates()
+        .iter()
+        .flat_map(|task| &task.compliance)
+        .all(|note| !note.topic.contains("LIHTC") && !note.detail.contains("LIHTC")));
+    let inspection = market_rate
+        .task_templates()
+        .iter()
+        .find(|task| task.key == "leasing_conduct_move_in_inspection")
+        .expect("inspection inherited");
+    assert_eq!(inspection.prerequisites, vec!["leasing_collect_funds"]);
+
+    let section_8 = registry.get("section_8").expect("section 8 registered");
+    let keys = task_keys(section_8);
+    assert!(keys.contains(&"leasing_hqs_inspection"));
+    assert!(keys.contains(&"leasing_hap_contract"));
+    assert!(!keys.contains(&"leasing_lihtc_certification"));
+
+    let student = registry.get("student_housing").expect("student registered");
+    let prepare = student
+        .task_templates()
+        .iter()
+        .find(|task| task.key == "leasing_prepare_agreement")
+        .expect("prepare agreement replaced");
+    assert!(prepare
+        .prerequisites
+        .contains(&"screening_guarantor_verification".to_string()));
+
+    assert!(matches!(
+        registry.select(Some("co_op")),
+        Err(VacancyError::UnknownBlueprint(ref name)) if name == "co_op"
+    ));
+    assert_eq!(
+        task_keys(registry.select(None).expect("default program")),
+        task_keys(&VacancyWorkflowBlueprint::standard())
+    );
+}
+
+#[test]
+fn registry_resolves_extends_against_registered_programs() {
+    let mut registry = BlueprintRegistry::builtin();
+    let overrides = r#"
+extends: market_rate
+remove:
+  - marketing_update_appfolio
+tasks:
+  - key: marketing_publish_listing
+    name: Publish Listing to Senior Housing Networks
+    stage: marketing_and_advertising
+    role: leasing_agent
+    due: { days_from_vacancy: 1 }
+"#;
+    let blueprint = registry
+        .register_reader("senior", Cursor::new(overrides), BlueprintFormat::Yaml)
+        .expect("override resolves");
+    assert_eq!(blueprint.task_templates().len(), 8);
+    let publish = &blueprint.task_templates()[0];
+    assert_eq!(publish.name, "Publish Listing to Senior Housing Networks");
+    assert_eq!(publish.due, DueDateRule::DaysFromVacancy(1));
+    assert!(registry.get("senior").is_some());
+
+    let unknown_base = "extends = \"co_op\"\n";
+    let error = registry
+        .register_reader(
+            "co_op_plus",
+            Cursor::new(unknown_base),
+            BlueprintFormat::Toml,
+        )
+        .expect_err("unknown base rejected");
+    assert!(matches!(error, BlueprintError::UnknownBase(ref name) if name == "co_op"));
+
+    let unknown_removal = "extends = \"lihtc\"\nremove = [\"leasing_pet_screening\"]\n";
+    let error = registry
+        .register_reader(
+            "broken",
+            Cursor::new(unknown_removal),
+            BlueprintFormat::Toml,
+        )
+        .expect_err("unknown removal rejected");
+    assert!(matches!(
+        error,
+        BlueprintError::UnknownRemovedTask(ref key) if key == "leasing_pet_screening"
+    ));
+
+    let standalone = VacancyWorkflowBlueprint::from_reader(
+        Cursor::new("extends = \"section_8\"\n"),
+        BlueprintFormat::Toml,
+    )
+    .expect("from_reader resolves bundled bases");
+    assert_eq!(
+        task_keys(&standalone),
+        task_keys(registry.get("section_8").expect("section 8 registered"))
+    );
+}
+
+#[test]
+fn program_blueprints_keep_the_weights_of_tasks_they_replace() {
+    let registry = BlueprintRegistry::builtin();
+    let base = registry
+        .get(BlueprintRegistry::DEFAULT_PROGRAM)
+        .expect("base program registered");
+
+    for program in registry.names() {
+        let blueprint = registry.get(program).expect("listed program registered");
+        for task in blueprint.task_templates() {
+            if let Some(original) = base
+                .task_templates()
+                .iter()
+                .find(|original| original.key == task.key)
+            {
+                assert_eq!(
+                    task.weight, original.weight,
+                    "{program} changes the weight of {}",
+                    task.key
+                );
+            }
+        }
+    }
+}
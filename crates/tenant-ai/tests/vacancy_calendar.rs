@@ -0,0 +1,167 @@
+use chrono::NaiveDate;
+use std::io::Cursor;
+use tenant_ai::workflows::vacancy::{
+    BusinessCalendar, CalendarError, VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+}
+
+#[test]
+fn calendar_loads_csv_and_ical_holidays() {
+    let csv = "date,name\n2025-11-27,Thanksgiving\n2025-12-25,Christmas\n";
+    let calendar =
+        BusinessCalendar::from_csv_reader(Cursor::new(csv)).expect("csv calendar parses");
+    assert_eq!(
+        calendar.holidays().collect::<Vec<_>>(),
+        vec![date(2025, 11, 27), date(2025, 12, 25)]
+    );
+
+    let ical = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20251127\r\nSUMMARY:Thanksgiving\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nDTSTART:20260101T000000Z\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+    let calendar =
+        BusinessCalendar::from_ical_reader(Cursor::new(ical)).expect("ical calendar parses");
+    assert_eq!(
+        calendar.holidays().collect::<Vec<_>>(),
+        vec![date(2025, 11, 27), date(2026, 1, 1)]
+    );
+
+    let error = BusinessCalendar::from_csv_reader(Cursor::new("2025-11-27\nnot-a-date\n"))
+        .expect_err("invalid date rejected");
+    assert!(matches!(error, CalendarError::InvalidDate { line: 2, .. }));
+}
+
+#[test]
+fn unsupported_calendar_extension_is_reported_before_opening_the_file() {
+    let error = BusinessCalendar::from_path("missing-holidays.txt")
+        .expect_err("unsupported extension rejected");
+    assert!(matches!(error, CalendarError::UnsupportedFormat(_)));
+
+    let error =
+        BusinessCalendar::from_path("missing-holidays.csv").expect_err("missing file rejected");
+    assert!(matches!(error, CalendarError::Io(_)));
+}
+
+#[test]
+fn ical_reads_event_dates_only_and_spans_multi_day_closures() {
+    let ical = "BEGIN:VCALENDAR\r\n\
+BEGIN:VTIMEZONE\r\n\
+TZID:America/New_York\r\n\
+BEGIN:STANDARD\r\n\
+DTSTART:19701101T020000\r\n\
+TZOFFSETFROM:-0400\r\n\
+TZOFFSETTO:-0500\r\n\
+END:STANDARD\r\n\
+BEGIN:DAYLIGHT\r\n\
+DTSTART:19700308T020000\r\n\
+TZOFFSETFROM:-0500\r\n\
+TZOFFSETTO:-0400\r\n\
+END:DAYLIGHT\r\n\
+END:VTIMEZONE\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;VALUE=DATE:20251224\r\n\
+DTEND;VALUE=DATE:20251227\r\n\
+SUMMARY:Winter closure\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;TZID=America/New_York:20260119T090000\r\n\
+DTEND;TZID=America/New_York:20260120T170000\r\n\
+SUMMARY:Office offsite\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+    let calendar =
+        BusinessCalendar::from_ical_reader(Cursor::new(ical)).expect("ical calendar parses");
+
+    assert_eq!(
+        calendar.holidays().collect::<Vec<_>>(),
+        vec![
+            date(2025, 12, 24),
+            date(2025, 12, 25),
+            date(2025, 12, 26),
+            date(2026, 1, 19),
+            date(2026, 1, 20),
+        ]
+    );
+}
+
+#[test]
+fn ical_unfolds_continuation_lines_before_parsing() {
+    let ical = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;TZID=America/\r\n\
+\x20New_York:20260119T090000\r\n\
+DTEND;TZID=America/New_York:2026012\r\n\
+\t0T170000\r\n\
+SUMMARY:Office offsite for the regional leasing and maintenance teams, exported\r\n\
+\x20 from Outlook\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+    let calendar =
+        BusinessCalendar::from_ical_reader(Cursor::new(ical)).expect("folded ical parses");
+
+    assert_eq!(
+        calendar.holidays().collect::<Vec<_>>(),
+        vec![date(2026, 1, 19), date(2026, 1, 20)]
+    );
+}
+
+#[test]
+fn business_day_rules_skip_weekends_and_holidays() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let vacancy_start = date(2025, 11, 26);
+    let target_move_in = date(2025, 12, 10);
+    let calendar = BusinessCalendar::with_holidays([date(2025, 11, 27), date(2025, 11, 28)]);
+    let instance =
+        VacancyWorkflowInstance::with_calendar(&blueprint, vacancy_start, target_move_in, calendar);
+
+    let process_applications = instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "screening_process_applications")
+        .expect("process applications task");
+    assert_eq!(process_applications.due_date, date(2025, 12, 2));
+
+    let mut weekend_start =
+        VacancyWorkflowInstance::new(&blueprint, date(2025, 9, 26), target_move_in);
+    let due_after_weekend = weekend_start
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "screening_process_applications")
+        .map(|task| task.due_date);
+    assert_eq!(due_after_weekend, Some(date(2025, 9, 30)));
+
+    weekend_start.set_calendar(BusinessCalendar::with_holidays([date(2025, 9, 29)]));
+    let due_after_holiday = weekend_start
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "screening_process_applications")
+        .map(|task| task.due_date);
+    assert_eq!(due_after_holiday, Some(date(2025, 10, 1)));
+}
+
+#[test]
+fn overdue_logic_waits_for_next_business_day() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let vacancy_start = date(2025, 9, 24);
+    let target_move_in = date(2025, 10, 8);
+    let instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+
+    let lihtc = instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "leasing_lihtc_certification")
+        .expect("lihtc task");
+    assert_eq!(lihtc.due_date, date(2025, 10, 5), "due on a Sunday");
+
+    let monday = instance.report(date(2025, 10, 6));
+    assert!(monday
+        .overdue_tasks
+        .iter()
+        .all(|task| task.key != "leasing_lihtc_certification"));
+
+    let tuesday = instance.report(date(2025, 10, 7));
+    assert!(tuesday
+        .overdue_tasks
+        .iter()
+        .any(|task| task.key == "leasing_lihtc_certification"));
+}
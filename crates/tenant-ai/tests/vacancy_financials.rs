@@ -0,0 +1,98 @@
+use chrono::{Duration, NaiveDate};
+use tenant_ai::workflows::vacancy::{
+    applications::VacancyListingSnapshot, report::VacancyLoss, VacancyWorkflowBlueprint,
+    VacancyWorkflowInstance,
+};
+
+fn vacancy_start() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start date")
+}
+
+fn instance() -> VacancyWorkflowInstance {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    VacancyWorkflowInstance::new(
+        &blueprint,
+        vacancy_start(),
+        vacancy_start() + Duration::days(14),
+    )
+}
+
+#[test]
+fn vacancy_loss_splits_incurred_and_remaining_rent() {
+    let loss = VacancyLoss::new(
+        1825,
+        vacancy_start(),
+        vacancy_start() + Duration::days(14),
+        vacancy_start() + Duration::days(5),
+    );
+
+    assert_eq!(loss.daily_loss, 60.0);
+    assert_eq!(loss.days_on_market, 5);
+    assert_eq!(loss.incurred_loss, 300.0);
+    assert_eq!(loss.remaining_loss, 540.0);
+    assert_eq!(loss.projected_loss, 840.0);
+}
+
+#[test]
+fn vacancy_loss_keeps_accruing_after_move_in_slips() {
+    let loss = VacancyLoss::new(
+        1825,
+        vacancy_start(),
+        vacancy_start() + Duration::days(14),
+        vacancy_start() + Duration::days(20),
+    );
+
+    assert_eq!(loss.incurred_loss, 1200.0);
+    assert_eq!(loss.projected_loss, 1200.0);
+    assert_eq!(loss.remaining_loss, 0.0);
+
+    let before_start = VacancyLoss::new(
+        1825,
+        vacancy_start(),
+        vacancy_start() + Duration::days(14),
+        vacancy_start() - Duration::days(2),
+    );
+    assert_eq!(before_start.days_on_market, 0);
+    assert_eq!(before_start.incurred_loss, 0.0);
+}
+
+#[test]
+fn insights_price_vacancy_from_listing_rent() {
+    let mut instance = instance();
+    let today = vacancy_start() + Duration::days(3);
+    let summary = instance.report(today).summary();
+    let unpriced = summary.insights(
+        &instance,
+        instance.vacancy_start(),
+        instance.target_move_in(),
+        today,
+    );
+    assert!(unpriced.vacancy_loss.is_none());
+
+    instance.apply_listing(&VacancyListingSnapshot {
+        unit_id: "101".to_string(),
+        property_code: "OAK".to_string(),
+        listed_rent: 1825,
+        available_on: instance.target_move_in(),
+        deposit_required: 1825,
+    });
+    let priced = summary.insights(
+        &instance,
+        instance.vacancy_start(),
+        instance.target_move_in(),
+        today,
+    );
+    let loss = priced
+        .vacancy_loss
+        .expect("listing rent prices the vacancy");
+    assert_eq!(loss.incurred_loss, 180.0);
+    assert!(priced
+        .ai_observations
+        .iter()
+        .any(|note| note.contains("$180")));
+
+    let json = serde_json::to_string(&instance).expect("instance serializes");
+    let restored: VacancyWorkflowInstance =
+        serde_json::from_str(&json).expect("instance deserializes");
+    assert_eq!(restored.monthly_rent(), Some(1825));
+}
@@ -0,0 +1,176 @@
+use chrono::{Duration, NaiveDate};
+use std::io::Cursor;
+use tenant_ai::workflows::apollo::{ApolloImportOptions, ApolloNameMap, ApolloVacancyImporter};
+use tenant_ai::workflows::vacancy::{
+    domain::TaskStatus, BusinessCalendar, DurationHistory, ForecastOptions, VacancyForecast,
+    VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+};
+
+fn vacancy_start() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start date")
+}
+
+fn target_move_in() -> NaiveDate {
+    vacancy_start() + Duration::days(14)
+}
+
+fn instance() -> VacancyWorkflowInstance {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    VacancyWorkflowInstance::new(&blueprint, vacancy_start(), target_move_in())
+}
+
+fn forecast(
+    instance: &VacancyWorkflowInstance,
+    today: NaiveDate,
+    history: &DurationHistory,
+) -> VacancyForecast {
+    instance
+        .report(today)
+        .summary()
+        .forecast(instance, today, history, &ForecastOptions::default())
+}
+
+#[test]
+fn forecast_is_reproducible_for_a_seed() {
+    let instance = instance();
+    let first = forecast(&instance, vacancy_start(), &DurationHistory::new());
+    let second = forecast(&instance, vacancy_start(), &DurationHistory::new());
+
+    assert_eq!(first.runs, ForecastOptions::DEFAULT_RUNS);
+    assert_eq!(first.move_in_ready_p50, second.move_in_ready_p50);
+    assert_eq!(first.move_in_ready_p90, second.move_in_ready_p90);
+    assert_eq!(first.on_time_probability, second.on_time_probability);
+    assert!(first.move_in_ready_p50 <= first.move_in_ready_p90);
+    assert!(first.move_in_ready_p50 > vacancy_start());
+    assert!(first
+        .stages
+        .iter()
+        .all(|stage| stage.p50 <= stage.p90 && stage.p90 <= first.move_in_ready_p90));
+    assert!(first.history_backed_tasks.is_empty());
+}
+
+#[test]
+fn completed_vacancy_is_ready_today_with_certainty() {
+    let mut instance = instance();
+    let today = vacancy_start() + Duration::days(6);
+    let keys: Vec<String> = instance
+        .tasks()
+        .iter()
+        .map(|task| task.template.key.clone())
+        .collect();
+    for _ in 0..keys.len() {
+        for key in &keys {
+            let _ = instance.set_status(key, TaskStatus::Completed, Some(today));
+        }
+    }
+    assert!(instance
+        .tasks()
+        .iter()
+        .all(|task| task.status == TaskStatus::Completed));
+
+    let forecast = forecast(&instance, today, &DurationHistory::new());
+
+    assert_eq!(forecast.move_in_ready_p50, today);
+    assert_eq!(forecast.move_in_ready_p90, today);
+    assert_eq!(forecast.on_time_probability, 1.0);
+}
+
+#[test]
+fn slow_history_pushes_move_in_past_target() {
+    let instance = instance();
+    let mut history = DurationHistory::new();
+    for days in [20, 25, 30] {
+        history.record("marketing_publish_listing", days);
+    }
+
+    let baseline = forecast(&instance, vacancy_start(), &DurationHistory::new());
+    let slow = forecast(&instance, vacancy_start(), &history);
+
+    assert!(slow.move_in_ready_p50 > target_move_in());
+    assert!(slow.move_in_ready_p50 > baseline.move_in_ready_p50);
+    assert_eq!(slow.on_time_probability, 0.0);
+    assert_eq!(
+        slow.history_backed_tasks,
+        vec!["marketing_publish_listing".to_string()]
+    );
+}
+
+#[test]
+fn sparse_history_falls_back_to_blueprint_durations() {
+    let instance = instance();
+    let mut history = DurationHistory::new();
+    history.record("marketing_publish_listing", 30);
+    history.record("marketing_publish_listing", 30);
+
+    let baseline = forecast(&instance, vacancy_start(), &DurationHistory::new());
+    let sparse = forecast(&instance, vacancy_start(), &history);
+
+    assert_eq!(sparse.move_in_ready_p50, baseline.move_in_ready_p50);
+    assert!(sparse.history_backed_tasks.is_empty());
+}
+
+#[test]
+fn holidays_push_the_forecast_later() {
+    let baseline = forecast(&instance(), vacancy_start(), &DurationHistory::new());
+    let mut closed = instance();
+    closed.set_calendar(BusinessCalendar::with_holidays(
+        (1..=3).map(|offset| vacancy_start() + Duration::days(offset)),
+    ));
+
+    let forecast = forecast(&closed, vacancy_start(), &DurationHistory::new());
+
+    assert!(forecast.move_in_ready_p50 > baseline.move_in_ready_p50);
+}
+
+#[test]
+fn apollo_history_learns_created_to_completed_days() {
+    let csv = "Name,Created At,Completed At,Last Modified\n\
+Create and Publish Listing - Leasing Agent,2025-08-01T10:00:00Z,2025-08-04T12:00:00Z,2025-08-04T12:00:00Z\n\
+Create and Publish Listing - Leasing Agent,2025-08-10,2025-08-11,2025-08-11\n\
+Collect Funds - Property Manager / Accounting,2025-08-12T10:00:00Z,,2025-08-13T10:00:00Z\n\
+Unknown Task,2025-08-01,2025-08-09,2025-08-09\n";
+
+    let history =
+        ApolloVacancyImporter::duration_history(Cursor::new(csv), &BusinessCalendar::default())
+            .expect("history parses");
+
+    // Friday to Monday is one business day; Sunday to Monday is none.
+    assert_eq!(history.samples("marketing_publish_listing"), &[1, 0]);
+    assert!(history.samples("leasing_collect_funds").is_empty());
+    assert!(!history.is_empty());
+}
+
+#[test]
+fn apollo_history_resolves_names_like_the_import() {
+    let csv = "Name,Created At,Completed At,Last Modified\n\
+List unit on Zillow,2025-08-04,2025-08-06,2025-08-06\n\
+Key handoff,2025-08-04,2025-08-05,2025-08-05\n\
+Create and Publsh Listing - Leasing Agnt,2025-08-11,2025-08-12,2025-08-12\n";
+    let options = ApolloImportOptions {
+        name_map: [("List unit on Zillow", "marketing_publish_listing")]
+            .into_iter()
+            .collect(),
+        configured_name_map: [("Key handoff", "handoff_start_new_resident_workflow")]
+            .into_iter()
+            .collect::<ApolloNameMap>(),
+        ..ApolloImportOptions::default()
+    };
+
+    let history = ApolloVacancyImporter::duration_history_with_options(
+        Cursor::new(csv),
+        &VacancyWorkflowBlueprint::standard(),
+        &BusinessCalendar::default(),
+        &options,
+    )
+    .expect("history parses");
+
+    assert_eq!(history.samples("marketing_publish_listing"), &[2, 1]);
+    assert_eq!(history.samples("handoff_start_new_resident_workflow"), &[1]);
+    assert!(ApolloVacancyImporter::duration_history(
+        Cursor::new(csv),
+        &BusinessCalendar::default()
+    )
+    .expect("history parses")
+    .samples("handoff_start_new_resident_workflow")
+    .is_empty());
+}
@@ -0,0 +1,142 @@
+use chrono::{Duration, NaiveDate};
+use tenant_ai::workflows::vacancy::{
+    report::{InsightContext, InsightKind, InsightRule, InsightRuleRegistry, TaskCompletionScorer},
+    VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+};
+
+fn vacancy_start() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start date")
+}
+
+fn instance() -> VacancyWorkflowInstance {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    VacancyWorkflowInstance::new(
+        &blueprint,
+        vacancy_start(),
+        vacancy_start() + Duration::days(14),
+    )
+}
+
+/// Property-specific rule: a high-rise needs its elevator reserved before move-in week.
+struct ElevatorReservationRule {
+    priority: i32,
+}
+
+impl InsightRule for ElevatorReservationRule {
+    fn id(&self) -> &str {
+        "elevator_reservation"
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    fn evaluate(&self, context: &InsightContext<'_>) -> Vec<(InsightKind, String)> {
+        if context.days_until_move_in > 7 {
+            return Vec::new();
+        }
+        vec![(
+            InsightKind::Recommendation,
+            "Reserve the freight elevator for move-in day".to_string(),
+        )]
+    }
+}
+
+fn insights_with(
+    rules: &InsightRuleRegistry,
+    today: NaiveDate,
+) -> tenant_ai::workflows::vacancy::report::views::VacancyInsights {
+    let instance = instance();
+    let summary = instance.report(today).summary();
+    summary.insights_with_rules(
+        &instance,
+        instance.vacancy_start(),
+        instance.target_move_in(),
+        today,
+        &TaskCompletionScorer,
+        rules,
+    )
+}
+
+#[test]
+fn standard_rules_match_default_insights() {
+    let today = vacancy_start() + Duration::days(10);
+    let instance = instance();
+    let summary = instance.report(today).summary();
+    let default = summary.insights(
+        &instance,
+        instance.vacancy_start(),
+        instance.target_move_in(),
+        today,
+    );
+    let standard = insights_with(&InsightRuleRegistry::standard(), today);
+
+    assert_eq!(default.ai_observations, standard.ai_observations);
+    assert_eq!(default.recommended_actions, standard.recommended_actions);
+    assert!(standard.findings.iter().any(
+        |finding| finding.rule_id == "behind_pace" && finding.kind == InsightKind::Observation
+    ));
+    assert!(standard
+        .findings
+        .windows(2)
+        .all(|pair| pair[0].priority >= pair[1].priority));
+}
+
+#[test]
+fn custom_rule_is_ordered_by_priority() {
+    let today = vacancy_start() + Duration::days(10);
+    let mut rules = InsightRuleRegistry::standard();
+    rules.register(ElevatorReservationRule { priority: 200 });
+
+    let insights = insights_with(&rules, today);
+    assert_eq!(
+        insights.recommended_actions.first().map(String::as_str),
+        Some("Reserve the freight elevator for move-in day")
+    );
+    let finding = &insights.findings[0];
+    assert_eq!(finding.rule_id, "elevator_reservation");
+    assert_eq!(finding.priority, 200);
+
+    rules.register(ElevatorReservationRule { priority: 0 });
+    let insights = insights_with(&rules, today);
+    assert_eq!(
+        insights.recommended_actions.last().map(String::as_str),
+        Some("Reserve the freight elevator for move-in day")
+    );
+    assert_eq!(
+        rules
+            .rule_ids()
+            .filter(|id| *id == "elevator_reservation")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn removed_rules_stop_contributing() {
+    let today = vacancy_start() + Duration::days(10);
+    let mut rules = InsightRuleRegistry::standard();
+    assert!(rules.remove("move_in_imminent"));
+    assert!(!rules.remove("move_in_imminent"));
+
+    let insights = insights_with(&rules, today);
+    assert!(insights
+        .findings
+        .iter()
+        .all(|finding| finding.rule_id != "move_in_imminent"));
+    assert!(!insights
+        .ai_observations
+        .iter()
+        .any(|note| note.contains("until target move-in")));
+}
+
+#[test]
+fn empty_registry_falls_back_to_steady_state_observation() {
+    let insights = insights_with(&InsightRuleRegistry::new(), vacancy_start());
+    assert!(insights.findings.is_empty());
+    assert!(insights.recommended_actions.is_empty());
+    assert_eq!(
+        insights.ai_observations,
+        vec!["No blockers detected; maintain current automation cadence".to_string()]
+    );
+}
@@ -0,0 +1,114 @@
+use chrono::{Duration, NaiveDate};
+use tenant_ai::workflows::vacancy::{
+    domain::{TaskStatus, VacancyStage},
+    portfolio_report,
+    report::views::ReadinessLevel,
+    VacancyId, VacancyRecord, VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+};
+
+fn vacancy_start() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start date")
+}
+
+fn vacancy(property_code: &str, unit_id: &str, move_in_after_days: i64) -> VacancyRecord {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    VacancyRecord {
+        id: VacancyId::new(property_code, unit_id).expect("valid vacancy id"),
+        instance: VacancyWorkflowInstance::new(
+            &blueprint,
+            vacancy_start(),
+            vacancy_start() + Duration::days(move_in_after_days),
+        ),
+        version: 0,
+    }
+}
+
+fn complete_all(record: &mut VacancyRecord) {
+    while let Some(key) = record
+        .instance
+        .report(vacancy_start())
+        .actionable_tasks
+        .first()
+        .map(|task| task.key.clone())
+    {
+        record
+            .instance
+            .set_status(&key, TaskStatus::Completed, Some(vacancy_start()))
+            .expect("actionable tasks can complete");
+    }
+}
+
+#[test]
+fn portfolio_rolls_up_open_vacancies() {
+    let mut marketed = vacancy("OAK", "101", 14);
+    marketed
+        .instance
+        .set_status(
+            "marketing_publish_listing",
+            TaskStatus::Completed,
+            Some(vacancy_start()),
+        )
+        .expect("publish listing has no prerequisites");
+    let untouched = vacancy("OAK", "102", 10);
+    let other_property = vacancy("ELM", "7", 21);
+    let mut finished = vacancy("ELM", "8", 14);
+    complete_all(&mut finished);
+
+    let today = vacancy_start() + Duration::days(9);
+    let portfolio = portfolio_report(&[marketed, untouched, other_property, finished], today, 5);
+
+    assert_eq!(
+        portfolio.open_vacancies, 3,
+        "completed vacancies are skipped"
+    );
+
+    let marketing = portfolio
+        .stage_progress
+        .iter()
+        .find(|entry| entry.stage == VacancyStage::MarketingAndAdvertising)
+        .expect("marketing stage present");
+    assert_eq!(marketing.total, 6);
+    assert_eq!(marketing.completed, 1);
+
+    let ranked: Vec<&str> = portfolio
+        .units
+        .iter()
+        .map(|unit| unit.vacancy_id.as_str())
+        .collect();
+    assert_eq!(ranked, vec!["OAK:102", "ELM:7", "OAK:101"]);
+    assert_eq!(portfolio.units[0].rank, 1);
+
+    let oak = portfolio
+        .properties
+        .iter()
+        .find(|property| property.property_code == "OAK")
+        .expect("OAK property present");
+    assert_eq!(oak.open_vacancies, 2);
+    let at_risk_units = portfolio
+        .units
+        .iter()
+        .filter(|unit| {
+            unit.property_code == "OAK" && unit.readiness_level == ReadinessLevel::AtRisk
+        })
+        .count();
+    assert_eq!(oak.at_risk, at_risk_units);
+
+    assert_eq!(portfolio.top_overdue_tasks.len(), 5);
+    assert!(portfolio
+        .top_overdue_tasks
+        .windows(2)
+        .all(|pair| pair[0].days_overdue >= pair[1].days_overdue));
+    assert!(portfolio
+        .top_overdue_tasks
+        .iter()
+        .all(|overdue| overdue.task.due_date < today));
+}
+
+#[test]
+fn empty_portfolio_reports_nothing() {
+    let portfolio = portfolio_report(&[], vacancy_start(), 10);
+    assert_eq!(portfolio.open_vacancies, 0);
+    assert!(portfolio.units.is_empty());
+    assert!(portfolio.stage_progress.is_empty());
+    assert!(portfolio.top_overdue_tasks.is_empty());
+}
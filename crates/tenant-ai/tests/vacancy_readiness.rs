@@ -0,0 +1,130 @@
+use chrono::{Duration, NaiveDate};
+use tenant_ai::workflows::vacancy::{
+    domain::{TaskStatus, VacancyStage},
+    report::{CriticalityScorer, ReadinessModel, ReadinessScorer, TaskCompletionScorer},
+    VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+};
+
+fn vacancy_dates() -> (NaiveDate, NaiveDate) {
+    let vacancy_start = NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start date");
+    (vacancy_start, vacancy_start + Duration::days(14))
+}
+
+fn instance_with_marketing_done() -> VacancyWorkflowInstance {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+    for key in ["marketing_publish_listing", "marketing_update_appfolio"] {
+        instance
+            .set_status(key, TaskStatus::Completed, Some(vacancy_start))
+            .expect("marketing tasks have no prerequisites");
+    }
+    instance
+}
+
+#[test]
+fn default_scorer_matches_insights_readiness() {
+    let instance = instance_with_marketing_done();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let today = vacancy_start + Duration::days(2);
+
+    assert_eq!(TaskCompletionScorer.score(&instance, today), 20);
+    let insights =
+        instance
+            .report(today)
+            .summary()
+            .insights(&instance, vacancy_start, target_move_in, today);
+    assert_eq!(insights.readiness_score, 20);
+}
+
+#[test]
+fn criticality_scorer_discounts_low_weight_early_work() {
+    let instance = instance_with_marketing_done();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let today = vacancy_start + Duration::days(2);
+
+    let weighted = CriticalityScorer::default().score(&instance, today);
+    assert!(
+        weighted < 20,
+        "marketing-only progress should count for less than a fifth, got {weighted}"
+    );
+
+    let summary = instance.report(today).summary();
+    let insights = summary.insights_with_scorer(
+        &instance,
+        vacancy_start,
+        target_move_in,
+        today,
+        ReadinessModel::Criticality.scorer().as_ref(),
+    );
+    assert_eq!(insights.readiness_score, weighted);
+}
+
+#[test]
+fn criticality_scorer_uses_stage_weights_and_due_proximity() {
+    let instance = instance_with_marketing_done();
+    let (vacancy_start, _) = vacancy_dates();
+
+    let mut marketing_heavy = CriticalityScorer::default();
+    marketing_heavy
+        .stage_weights
+        .insert(VacancyStage::MarketingAndAdvertising, 10.0);
+    assert!(
+        marketing_heavy.score(&instance, vacancy_start)
+            > CriticalityScorer::default().score(&instance, vacancy_start)
+    );
+
+    let scorer = CriticalityScorer::default();
+    assert!(
+        scorer.score(&instance, vacancy_start)
+            > scorer.score(&instance, vacancy_start + Duration::days(20)),
+        "open work weighs more once it comes due"
+    );
+}
+
+#[test]
+fn criticality_scorer_ignores_due_dates_of_completed_tasks() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+    let last_due = instance
+        .tasks()
+        .iter()
+        .map(|task| task.due_date)
+        .max()
+        .expect("blueprint has tasks");
+    while let Some(key) = instance
+        .report(vacancy_start)
+        .actionable_tasks
+        .iter()
+        .find(|task| task.due_date < last_due)
+        .map(|task| task.key.clone())
+    {
+        instance
+            .set_status(&key, TaskStatus::Completed, Some(vacancy_start))
+            .expect("actionable tasks can complete");
+    }
+
+    // A one-day window keeps the open tasks, all due on `last_due`, at base weight.
+    let scorer = CriticalityScorer {
+        due_window_days: 1,
+        ..CriticalityScorer::default()
+    };
+    assert_eq!(
+        scorer.score(&instance, vacancy_start),
+        scorer.score(&instance, last_due - Duration::days(1))
+    );
+}
+
+#[test]
+fn readiness_model_parses_keys() {
+    assert_eq!(
+        ReadinessModel::from_key("criticality"),
+        Some(ReadinessModel::Criticality)
+    );
+    assert_eq!(
+        ReadinessModel::from_key("task_completion"),
+        Some(ReadinessModel::TaskCompletion)
+    );
+    assert_eq!(ReadinessModel::from_key("vibes"), None);
+}
@@ -0,0 +1,190 @@
+use chrono::{Duration, NaiveDate};
+use std::path::PathBuf;
+use tenant_ai::workflows::vacancy::applications::RepositoryError;
+use tenant_ai::workflows::vacancy::{
+    domain::{TaskStatus, VacancyError},
+    BusinessCalendar, FileVacancyRepository, InMemoryVacancyRepository, VacancyId, VacancyRecord,
+    VacancyWorkflowBlueprint, VacancyWorkflowInstance, VacancyWorkflowRepository,
+};
+
+fn vacancy_record(property_code: &str, unit_id: &str) -> VacancyRecord {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let vacancy_start = NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start date");
+    let holiday = NaiveDate::from_ymd_opt(2025, 9, 26).expect("valid holiday");
+    VacancyRecord {
+        id: VacancyId::new(property_code, unit_id).expect("valid vacancy id"),
+        instance: VacancyWorkflowInstance::with_calendar(
+            &blueprint,
+            vacancy_start,
+            vacancy_start + Duration::days(14),
+            BusinessCalendar::with_holidays([holiday]),
+        ),
+        version: 0,
+    }
+}
+
+fn scratch_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("tenant-ai-{}-{}.json", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn exercise_repository<R: VacancyWorkflowRepository>(repository: &R) {
+    let mut record = vacancy_record("OAK", "101");
+    repository
+        .create(record.clone())
+        .expect("first create succeeds");
+    repository
+        .create(vacancy_record("OAK", "102"))
+        .expect("second unit is distinct");
+    repository
+        .create(vacancy_record("ELM", "101"))
+        .expect("same unit id at another property is distinct");
+
+    assert!(matches!(
+        repository.create(vacancy_record("OAK", "101")),
+        Err(RepositoryError::Conflict)
+    ));
+
+    record
+        .instance
+        .set_status("marketing_publish_listing", TaskStatus::Completed, None)
+        .expect("publish listing has no prerequisites");
+    repository.update(record.clone()).expect("update succeeds");
+    assert!(
+        matches!(
+            repository.update(record.clone()),
+            Err(RepositoryError::Conflict)
+        ),
+        "a write based on a stale read is rejected"
+    );
+
+    let fetched = repository
+        .fetch(&record.id)
+        .expect("fetch succeeds")
+        .expect("record exists");
+    let publish = fetched
+        .instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_publish_listing")
+        .expect("publish listing task present");
+    assert_eq!(publish.status, TaskStatus::Completed);
+    assert_eq!(publish.history().len(), 1);
+    assert_eq!(fetched.instance.calendar(), record.instance.calendar());
+    assert_eq!(fetched.version, record.version + 1);
+
+    let mut oak_units: Vec<String> = repository
+        .list_by_property("OAK")
+        .expect("list succeeds")
+        .into_iter()
+        .map(|record| record.id.unit_id)
+        .collect();
+    oak_units.sort();
+    assert_eq!(oak_units, vec!["101", "102"]);
+
+    assert!(matches!(
+        repository.update(vacancy_record("PINE", "1")),
+        Err(RepositoryError::NotFound)
+    ));
+    assert!(repository
+        .fetch(&VacancyId::new("PINE", "1").expect("valid vacancy id"))
+        .expect("fetch succeeds")
+        .is_none());
+}
+
+#[test]
+fn in_memory_repository_tracks_vacancies_by_unit() {
+    exercise_repository(&InMemoryVacancyRepository::new());
+}
+
+#[test]
+fn file_repository_tracks_vacancies_by_unit() {
+    let path = scratch_path("vacancy-repository");
+    exercise_repository(&FileVacancyRepository::open(&path).expect("store opens"));
+    std::fs::remove_file(&path).expect("store file written");
+}
+
+#[test]
+fn file_repository_survives_reopen() {
+    let path = scratch_path("vacancy-repository-reopen");
+    let record = vacancy_record("OAK", "201");
+    FileVacancyRepository::open(&path)
+        .expect("store opens")
+        .create(record.clone())
+        .expect("create succeeds");
+
+    let reopened = FileVacancyRepository::open(&path).expect("store reopens");
+    let fetched = reopened
+        .fetch(&record.id)
+        .expect("fetch succeeds")
+        .expect("record persisted");
+    assert_eq!(
+        fetched.instance.vacancy_start(),
+        record.instance.vacancy_start()
+    );
+    assert_eq!(
+        fetched.instance.tasks().len(),
+        record.instance.tasks().len()
+    );
+    std::fs::remove_file(&path).expect("store file written");
+}
+
+#[test]
+fn file_repository_rejects_records_with_a_corrupted_task_order() {
+    let path = scratch_path("vacancy-repository-corrupt-order");
+    let record = vacancy_record("OAK", "301");
+    FileVacancyRepository::open(&path)
+        .expect("store opens")
+        .create(record)
+        .expect("create succeeds");
+
+    let contents = std::fs::read_to_string(&path).expect("store file written");
+    let stored: serde_json::Value = serde_json::from_str(&contents).expect("store is json");
+    let corruptions: [fn(&mut Vec<serde_json::Value>); 2] = [
+        |order| order[0] = serde_json::json!(9_999),
+        |order| {
+            order.pop();
+        },
+    ];
+    for corrupt in corruptions {
+        let mut records = stored.clone();
+        corrupt(
+            records[0]["instance"]["order"]
+                .as_array_mut()
+                .expect("instance stores its task order"),
+        );
+        std::fs::write(&path, records.to_string()).expect("store file rewritten");
+        assert!(matches!(
+            FileVacancyRepository::open(&path),
+            Err(RepositoryError::Unavailable(_))
+        ));
+    }
+    std::fs::remove_file(&path).expect("store file written");
+}
+
+#[test]
+fn vacancy_id_round_trips_through_display() {
+    let id = VacancyId::new("OAK", "101").expect("valid vacancy id");
+    assert_eq!(id.to_string(), "OAK:101");
+    assert_eq!(VacancyId::parse("OAK:101"), Some(id));
+    assert_eq!(VacancyId::parse("OAK"), None);
+    assert_eq!(VacancyId::parse(":101"), None);
+    assert_eq!(VacancyId::parse("OAK:B:2"), None);
+}
+
+#[test]
+fn vacancy_id_rejects_parts_that_would_not_round_trip() {
+    for (property_code, unit_id) in [
+        ("OAK:EAST", "101"),
+        ("OAK", "B:2"),
+        ("", "101"),
+        ("OAK", ""),
+    ] {
+        let error = VacancyId::new(property_code, unit_id).expect_err("ambiguous id rejected");
+        assert!(matches!(error, VacancyError::InvalidVacancyId { .. }));
+    }
+
+    let id = VacancyId::new("OAK-EAST", "B-2").expect("valid vacancy id");
+    assert_eq!(VacancyId::parse(&id.to_string()), Some(id));
+}
@@ -0,0 +1,203 @@
+use chrono::{Duration, NaiveDate};
+use std::io::Cursor;
+use tenant_ai::workflows::vacancy::{
+    domain::{TaskStatus, VacancyRole},
+    AssignmentPolicy, RosterError, StaffAssigner, StaffMember, StaffRoster, UnknownAssignee,
+    VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+};
+
+fn vacancy_start() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start date")
+}
+
+fn instance() -> VacancyWorkflowInstance {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    VacancyWorkflowInstance::new(
+        &blueprint,
+        vacancy_start(),
+        vacancy_start() + Duration::days(14),
+    )
+}
+
+fn member(id: &str, roles: &[VacancyRole]) -> StaffMember {
+    StaffMember {
+        id: id.to_string(),
+        name: id.to_uppercase(),
+        roles: roles.to_vec(),
+    }
+}
+
+fn leasing_roster() -> StaffRoster {
+    StaffRoster::new(vec![
+        member("ana", &[VacancyRole::LeasingAgent]),
+        member("ben", &[VacancyRole::LeasingAgent]),
+        member("cy", &[VacancyRole::LeasingAgent]),
+    ])
+    .expect("unique roster")
+}
+
+fn open_load(instance: &VacancyWorkflowInstance, staff_id: &str) -> usize {
+    instance
+        .tasks()
+        .iter()
+        .filter(|task| {
+            task.assignee.as_deref() == Some(staff_id) && task.status != TaskStatus::Completed
+        })
+        .count()
+}
+
+#[test]
+fn round_robin_rotates_through_role_members() {
+    let roster = leasing_roster();
+    let mut instance = instance();
+
+    let assigned = StaffAssigner::new(&roster, AssignmentPolicy::RoundRobin).assign(&mut instance);
+
+    let leasing_tasks = instance
+        .tasks()
+        .iter()
+        .filter(|task| task.template.primary_role == VacancyRole::LeasingAgent)
+        .count();
+    assert_eq!(assigned.len(), leasing_tasks);
+    let rotation: Vec<&str> = assigned
+        .iter()
+        .take(3)
+        .map(|(_, staff_id)| staff_id.as_str())
+        .collect();
+    assert_eq!(rotation, vec!["ana", "ben", "cy"]);
+    assert!(instance
+        .tasks()
+        .iter()
+        .filter(|task| task.template.primary_role != VacancyRole::LeasingAgent)
+        .all(|task| task.assignee.is_none()));
+}
+
+#[test]
+fn least_loaded_balances_across_vacancies() {
+    let roster = leasing_roster();
+    let mut first = instance();
+    let mut second = instance();
+
+    let mut assigner = StaffAssigner::new(&roster, AssignmentPolicy::LeastLoaded);
+    assigner.assign(&mut first);
+    assigner.assign(&mut second);
+
+    let totals: Vec<usize> = ["ana", "ben", "cy"]
+        .iter()
+        .map(|id| open_load(&first, id) + open_load(&second, id))
+        .collect();
+    let spread = totals.iter().max().unwrap() - totals.iter().min().unwrap();
+    assert!(spread <= 1, "loads {:?} should be balanced", totals);
+}
+
+#[test]
+fn staffing_report_suggests_moving_work_off_overloaded_member() {
+    let roster = leasing_roster();
+    let mut instance = instance();
+    let leasing_keys: Vec<String> = instance
+        .tasks()
+        .iter()
+        .filter(|task| task.template.primary_role == VacancyRole::LeasingAgent)
+        .map(|task| task.template.key.clone())
+        .collect();
+    for key in &leasing_keys {
+        instance.assign(key, Some("ana")).expect("task exists");
+    }
+
+    let today = vacancy_start() + Duration::days(3);
+    let report = instance.report(today);
+    let summary = report.summary();
+    let ana_summary = summary
+        .staff_load
+        .iter()
+        .find(|entry| entry.assignee == "ana")
+        .expect("ana carries work");
+    assert_eq!(ana_summary.open, leasing_keys.len());
+
+    let staffing = instance.staffing(&roster, today);
+    let ana = staffing
+        .staff_load
+        .iter()
+        .find(|entry| entry.staff_id == "ana")
+        .expect("ana listed");
+    assert_eq!(ana.open, leasing_keys.len());
+    assert!(!staffing.reassignment_suggestions.is_empty());
+    assert!(staffing
+        .reassignment_suggestions
+        .iter()
+        .all(|suggestion| suggestion.from_staff_id == "ana" && suggestion.to_staff_id != "ana"));
+
+    let mut after: Vec<usize> = vec![ana.open, 0, 0];
+    for suggestion in &staffing.reassignment_suggestions {
+        after[0] -= 1;
+        match suggestion.to_staff_id.as_str() {
+            "ben" => after[1] += 1,
+            "cy" => after[2] += 1,
+            other => panic!("unexpected target {other}"),
+        }
+    }
+    assert!(after.iter().max().unwrap() - after.iter().min().unwrap() < 2);
+}
+
+#[test]
+fn staffing_report_lists_work_held_by_people_off_the_roster() {
+    let roster = leasing_roster();
+    let mut instance = instance();
+    instance
+        .assign("marketing_publish_listing", Some("dana"))
+        .expect("task exists");
+    instance
+        .assign("marketing_update_appfolio", Some("ana"))
+        .expect("task exists");
+
+    let staffing = instance.staffing(&roster, vacancy_start());
+
+    assert_eq!(
+        staffing.unknown_assignees,
+        vec![UnknownAssignee {
+            assignee: "dana".to_string(),
+            open: 1,
+            overdue: 0,
+        }]
+    );
+    assert_eq!(staffing.unassigned_open, instance.tasks().len() - 2);
+    let assigned: usize = staffing.staff_load.iter().map(|entry| entry.open).sum();
+    assert_eq!(assigned, 1);
+}
+
+#[test]
+fn assign_rejects_unknown_task() {
+    let mut instance = instance();
+    assert!(instance.assign("not_a_task", Some("ana")).is_err());
+}
+
+#[test]
+fn roster_loads_from_toml_and_rejects_duplicates() {
+    let toml = r#"
+[[members]]
+id = "ana"
+name = "Ana Ortiz"
+roles = ["leasing_agent", "property_manager"]
+
+[[members]]
+id = "max"
+name = "Max Reid"
+roles = ["compliance_coordinator"]
+"#;
+    let roster = StaffRoster::from_toml_reader(Cursor::new(toml)).expect("roster parses");
+    assert_eq!(roster.members().len(), 2);
+    assert_eq!(
+        roster
+            .members_for_role(VacancyRole::LeasingAgent)
+            .map(|member| member.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["ana"]
+    );
+
+    let duplicate = StaffRoster::new(vec![
+        member("ana", &[VacancyRole::LeasingAgent]),
+        member("ana", &[VacancyRole::PropertyManager]),
+    ])
+    .expect_err("duplicate ids rejected");
+    assert!(matches!(duplicate, RosterError::DuplicateMember(id) if id == "ana"));
+}
@@ -0,0 +1,113 @@
+use chrono::{Duration, NaiveDate};
+use tenant_ai::workflows::vacancy::{
+    domain::TaskStatus,
+    report::{views::ReadinessLevel, ReadinessSnapshot, ReadinessTrend},
+    VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+};
+
+fn vacancy_start() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 9, 24).expect("valid vacancy start date")
+}
+
+fn instance() -> VacancyWorkflowInstance {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    VacancyWorkflowInstance::new(
+        &blueprint,
+        vacancy_start(),
+        vacancy_start() + Duration::days(14),
+    )
+}
+
+fn snapshot(day: i64, score: u8, level: ReadinessLevel) -> ReadinessSnapshot {
+    ReadinessSnapshot {
+        date: vacancy_start() + Duration::days(day),
+        readiness_score: score,
+        readiness_level: level,
+        completed_tasks: 0,
+        open_tasks: 0,
+        overdue_tasks: 0,
+        critical_alerts: 0,
+    }
+}
+
+#[test]
+fn record_snapshot_keeps_one_point_per_day_in_date_order() {
+    let mut instance = instance();
+    let later = vacancy_start() + Duration::days(2);
+    let earlier = vacancy_start() + Duration::days(1);
+
+    instance.record_snapshot(later);
+    instance.record_snapshot(earlier);
+    instance
+        .set_status(
+            "marketing_publish_listing",
+            TaskStatus::Completed,
+            Some(later),
+        )
+        .expect("task exists");
+    let replaced = instance.record_snapshot(later).clone();
+
+    let dates: Vec<NaiveDate> = instance
+        .snapshots()
+        .iter()
+        .map(|snapshot| snapshot.date)
+        .collect();
+    assert_eq!(dates, vec![earlier, later]);
+    assert_eq!(replaced.completed_tasks, 1);
+    assert_eq!(
+        replaced.completed_tasks + replaced.open_tasks,
+        instance.tasks().len()
+    );
+    assert_eq!(instance.snapshots()[1], replaced);
+}
+
+#[test]
+fn snapshots_survive_serialization() {
+    let mut instance = instance();
+    instance.record_snapshot(vacancy_start() + Duration::days(3));
+
+    let json = serde_json::to_string(&instance).expect("instance serializes");
+    let restored: VacancyWorkflowInstance =
+        serde_json::from_str(&json).expect("instance deserializes");
+    assert_eq!(restored.snapshots(), instance.snapshots());
+}
+
+#[test]
+fn trend_reports_level_transitions_and_net_change() {
+    let trend = ReadinessTrend::from_snapshots(&[
+        snapshot(3, 40, ReadinessLevel::Monitor),
+        snapshot(1, 10, ReadinessLevel::Monitor),
+        snapshot(5, 45, ReadinessLevel::AtRisk),
+        snapshot(7, 85, ReadinessLevel::OnTrack),
+    ]);
+
+    assert_eq!(
+        trend.points.first().map(|point| point.readiness_score),
+        Some(10)
+    );
+    assert_eq!(trend.score_change, 75);
+    let transitions: Vec<(ReadinessLevel, ReadinessLevel)> = trend
+        .level_transitions
+        .iter()
+        .map(|transition| (transition.from, transition.to))
+        .collect();
+    assert_eq!(
+        transitions,
+        vec![
+            (ReadinessLevel::Monitor, ReadinessLevel::AtRisk),
+            (ReadinessLevel::AtRisk, ReadinessLevel::OnTrack),
+        ]
+    );
+    assert_eq!(
+        trend.level_transitions[0].date,
+        vacancy_start() + Duration::days(5)
+    );
+}
+
+#[test]
+fn empty_trend_has_no_change() {
+    let trend = instance().readiness_trend();
+    assert!(trend.points.is_empty());
+    assert!(trend.level_transitions.is_empty());
+    assert_eq!(trend.score_change, 0);
+}
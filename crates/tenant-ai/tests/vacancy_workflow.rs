@@ -1,7 +1,11 @@
-use chrono::{Duration, NaiveDate};
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
 use tenant_ai::workflows::vacancy::{
-    domain::{ComplianceSeverity, TaskStatus, VacancyError, VacancyRole, VacancyStage},
-    VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+    applications::{DocumentCategory, DocumentDescriptor},
+    domain::{
+        ComplianceEvidence, ComplianceSeverity, SeverityPolicy, StatusChange, TaskStatus,
+        VacancyError, VacancyRole, VacancyStage,
+    },
+    BusinessCalendar, VacancyWorkflowBlueprint, VacancyWorkflowInstance,
 };
 
 fn vacancy_dates() -> (NaiveDate, NaiveDate) {
@@ -29,7 +33,7 @@ fn blueprint_captures_required_vacancy_structure() {
     assert!(publish_listing
         .deliverables
         .iter()
-        .any(|step: &&str| step.contains("listing") && step.contains("photos")));
+        .any(|step| step.name.contains("listing") && step.name.contains("photos")));
     assert!(publish_listing
         .compliance
         .iter()
@@ -43,7 +47,7 @@ fn blueprint_captures_required_vacancy_structure() {
     assert!(manage_inquiries
         .deliverables
         .iter()
-        .any(|step: &&str| step.to_lowercase().contains("fair housing")));
+        .any(|step| step.name.to_lowercase().contains("fair housing")));
 
     let lease_tasks = blueprint.tasks_for_stage(VacancyStage::LeaseSigningAndMoveIn);
     assert!(lease_tasks
@@ -65,12 +69,8 @@ fn reporting_flags_overdue_and_compliance_gaps() {
         )
         .expect("able to mark task complete");
     instance
-        .set_status(
-            "screening_process_applications",
-            TaskStatus::InProgress,
-            None,
-        )
-        .expect("able to mark task in progress");
+        .set_status("screening_process_applications", TaskStatus::Blocked, None)
+        .expect("able to mark task blocked");
 
     let today = target_move_in - Duration::days(1);
     let report = instance.report(today);
@@ -220,3 +220,639 @@ fn set_status_returns_error_for_unknown_task_key() {
         other => panic!("expected task not found error, got {other:?}"),
     }
 }
+
+#[test]
+fn set_status_rejects_work_on_tasks_with_incomplete_prerequisites() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+
+    let result = instance.set_status(
+        "leasing_conduct_move_in_inspection",
+        TaskStatus::Completed,
+        Some(target_move_in),
+    );
+    match result {
+        Err(VacancyError::PrerequisitesIncomplete { task_key, pending }) => {
+            assert_eq!(task_key, "leasing_conduct_move_in_inspection");
+            assert_eq!(
+                pending,
+                vec!["leasing_collect_funds", "leasing_lihtc_certification"]
+            );
+        }
+        other => panic!("expected prerequisites error, got {other:?}"),
+    }
+
+    instance
+        .set_status(
+            "marketing_publish_listing",
+            TaskStatus::Completed,
+            Some(vacancy_start),
+        )
+        .expect("listing has no prerequisites");
+    instance
+        .set_status("screening_manage_inquiries", TaskStatus::InProgress, None)
+        .expect("inquiries unblocked once listing is published");
+}
+
+#[test]
+fn report_separates_actionable_and_waiting_tasks() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+
+    instance
+        .set_status(
+            "marketing_publish_listing",
+            TaskStatus::Completed,
+            Some(vacancy_start),
+        )
+        .expect("mark publish listing complete");
+
+    let summary = instance.report(vacancy_start).summary();
+
+    let actionable: Vec<&str> = summary
+        .actionable_tasks
+        .iter()
+        .map(|task| task.key.as_str())
+        .collect();
+    assert_eq!(
+        actionable,
+        vec!["marketing_update_appfolio", "screening_manage_inquiries"]
+    );
+
+    let waiting_inspection = summary
+        .waiting_tasks
+        .iter()
+        .find(|task| task.key == "leasing_conduct_move_in_inspection")
+        .expect("inspection waits on upstream work");
+    assert_eq!(
+        waiting_inspection.waiting_on,
+        vec!["leasing_collect_funds", "leasing_lihtc_certification"]
+    );
+    assert_eq!(
+        summary.actionable_tasks.len() + summary.waiting_tasks.len(),
+        9
+    );
+
+    let details = instance.task_details();
+    let process_applications = details
+        .iter()
+        .find(|task| task.key == "screening_process_applications")
+        .expect("process applications detail");
+    assert_eq!(
+        process_applications.prerequisites,
+        vec!["screening_manage_inquiries"]
+    );
+    assert_eq!(
+        process_applications.waiting_on,
+        vec!["screening_manage_inquiries"]
+    );
+}
+
+#[test]
+fn schedule_computes_slack_and_critical_path_against_move_in() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+
+    let schedule = instance.schedule(vacancy_start);
+    assert_eq!(schedule.len(), 10);
+
+    let appfolio = schedule
+        .iter()
+        .find(|entry| entry.key == "marketing_update_appfolio")
+        .expect("appfolio task scheduled");
+    assert_eq!(appfolio.earliest_start, vacancy_start);
+    assert_eq!(appfolio.latest_finish, target_move_in);
+    assert_eq!(appfolio.latest_start, target_move_in - Duration::days(1));
+    assert_eq!(
+        appfolio.slack_days, 9,
+        "business days, skipping two weekends"
+    );
+    assert!(!appfolio.critical);
+
+    let lihtc = schedule
+        .iter()
+        .find(|entry| entry.key == "leasing_lihtc_certification")
+        .expect("lihtc task scheduled");
+    assert_eq!(lihtc.earliest_start, vacancy_start + Duration::days(8));
+    assert_eq!(lihtc.latest_finish, target_move_in - Duration::days(1));
+    assert_eq!(lihtc.slack_days, 1);
+    assert!(
+        !lihtc.critical,
+        "lihtc does not drive the move-in inspection"
+    );
+
+    let critical: Vec<&str> = schedule
+        .iter()
+        .filter(|entry| entry.critical)
+        .map(|entry| entry.key.as_str())
+        .collect();
+    assert_eq!(
+        critical,
+        vec![
+            "marketing_publish_listing",
+            "screening_manage_inquiries",
+            "screening_process_applications",
+            "screening_notify_applicants",
+            "leasing_prepare_agreement",
+            "leasing_collect_funds",
+            "leasing_conduct_move_in_inspection",
+            "handoff_start_new_resident_workflow",
+        ]
+    );
+    assert!(schedule
+        .iter()
+        .filter(|entry| entry.critical)
+        .all(|entry| entry.slack_days == -1));
+
+    let mut with_holiday = instance.clone();
+    with_holiday.set_calendar(BusinessCalendar::with_holidays([
+        vacancy_start + Duration::days(2)
+    ]));
+    let appfolio = with_holiday
+        .schedule(vacancy_start)
+        .into_iter()
+        .find(|entry| entry.key == "marketing_update_appfolio")
+        .expect("appfolio task scheduled");
+    assert_eq!(appfolio.slack_days, 8);
+}
+
+#[test]
+fn insights_surface_negative_slack_when_move_in_is_too_close() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+    instance
+        .set_status(
+            "marketing_publish_listing",
+            TaskStatus::Completed,
+            Some(vacancy_start),
+        )
+        .expect("mark publish listing complete");
+
+    let today = target_move_in - Duration::days(2);
+    let summary = instance.report(today).summary();
+    let insights = summary.insights(&instance, vacancy_start, target_move_in, today);
+
+    assert_eq!(insights.schedule_slack_days, Some(-8));
+    assert_eq!(
+        insights.critical_path.first().map(|step| step.key.as_str()),
+        Some("screening_manage_inquiries")
+    );
+    assert!(insights
+        .critical_path
+        .iter()
+        .all(|step| step.key != "marketing_publish_listing"));
+    assert!(insights
+        .ai_observations
+        .iter()
+        .any(|note| note.contains("overruns target move-in by 8 day(s)")));
+}
+
+#[test]
+fn status_changes_append_to_task_history() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+    let started_at = Utc
+        .with_ymd_and_hms(2025, 9, 24, 9, 30, 0)
+        .single()
+        .expect("valid timestamp");
+
+    instance
+        .apply_status_change(
+            "marketing_publish_listing",
+            StatusChange {
+                status: TaskStatus::InProgress,
+                completed_on: None,
+                actor: Some(VacancyRole::LeasingAgent),
+                note: Some("Drafted listing copy and photo shot list".to_string()),
+                recorded_at: started_at,
+            },
+        )
+        .expect("start listing");
+    instance
+        .set_status(
+            "marketing_publish_listing",
+            TaskStatus::Completed,
+            Some(vacancy_start),
+        )
+        .expect("complete listing");
+    instance
+        .set_status(
+            "screening_process_applications",
+            TaskStatus::Completed,
+            Some(vacancy_start),
+        )
+        .expect_err("rejected change is not recorded");
+
+    let history = instance
+        .task_history("marketing_publish_listing")
+        .expect("history available");
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].recorded_at, started_at);
+    assert_eq!(history[0].from_status, TaskStatus::NotStarted);
+    assert_eq!(history[0].to_status, TaskStatus::InProgress);
+    assert_eq!(history[0].actor, Some(VacancyRole::LeasingAgent));
+    assert_eq!(history[1].from_status, TaskStatus::InProgress);
+    assert_eq!(history[1].completed_on, Some(vacancy_start));
+    assert!(history[1].actor.is_none());
+
+    assert!(instance
+        .task_history("screening_process_applications")
+        .expect("history available")
+        .is_empty());
+
+    let detail = instance
+        .task_details()
+        .into_iter()
+        .find(|task| task.key == "marketing_publish_listing")
+        .expect("detail present");
+    let payload = serde_json::to_value(&detail).expect("detail serializes");
+    assert_eq!(payload["history"][0]["actor"], "leasing_agent");
+    assert_eq!(
+        payload["history"][0]["note"],
+        "Drafted listing copy and photo shot list"
+    );
+}
+
+#[test]
+fn reschedule_redates_move_in_tasks_and_keeps_status() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+    instance
+        .set_status(
+            "marketing_publish_listing",
+            TaskStatus::Completed,
+            Some(vacancy_start),
+        )
+        .expect("publish listing has no prerequisites");
+    let original_handoff = instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "handoff_start_new_resident_workflow")
+        .expect("handoff task present")
+        .due_date;
+
+    let new_move_in = target_move_in + Duration::days(7);
+    let change = instance
+        .reschedule(vacancy_start, new_move_in)
+        .expect("move-in stays after vacancy start")
+        .expect("window changed")
+        .clone();
+
+    assert_eq!(change.previous_target_move_in, target_move_in);
+    assert_eq!(change.target_move_in, new_move_in);
+    assert_eq!(change.shifted_tasks.len(), 4);
+    assert!(change
+        .shifted_tasks
+        .iter()
+        .all(|shift| shift.due_date == shift.previous_due_date + Duration::days(7)));
+    assert_eq!(
+        change.summary(),
+        "Move-in moved from 2025-10-08 to 2025-10-15; 4 task(s) re-dated"
+    );
+
+    assert_eq!(instance.target_move_in(), new_move_in);
+    let publish = instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_publish_listing")
+        .expect("publish listing present");
+    assert_eq!(publish.status, TaskStatus::Completed);
+    assert_eq!(publish.history().len(), 1);
+    let handoff = instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "handoff_start_new_resident_workflow")
+        .expect("handoff task present");
+    assert_eq!(handoff.due_date, original_handoff + Duration::days(7));
+
+    let today = vacancy_start + Duration::days(3);
+    let summary = instance.report(today).summary();
+    assert_eq!(summary.schedule_changes.len(), 1);
+    let insights = summary.insights(&instance, vacancy_start, new_move_in, today);
+    assert!(insights
+        .ai_observations
+        .iter()
+        .any(|note| note == &change.summary()));
+}
+
+#[test]
+fn reschedule_rejects_inverted_window_and_skips_unchanged_dates() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+
+    let error = instance
+        .reschedule(target_move_in, vacancy_start)
+        .expect_err("move-in before vacancy start is rejected");
+    assert!(matches!(error, VacancyError::InvalidSchedule { .. }));
+    assert_eq!(instance.target_move_in(), target_move_in);
+
+    assert!(instance
+        .reschedule(vacancy_start, target_move_in)
+        .expect("unchanged window is accepted")
+        .is_none());
+    assert!(instance.schedule_changes().is_empty());
+}
+
+#[test]
+fn subtasks_drive_task_completion_and_readiness() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+    let task_count = instance.tasks().len() as f32;
+
+    let readiness = |instance: &VacancyWorkflowInstance| {
+        instance
+            .report(vacancy_start)
+            .summary()
+            .insights(instance, vacancy_start, target_move_in, vacancy_start)
+            .readiness_score
+    };
+    assert_eq!(readiness(&instance), 0);
+
+    instance
+        .set_subtask("marketing_publish_listing", 0, true, Some(vacancy_start))
+        .expect("first subtask checks off");
+    instance
+        .set_subtask("marketing_publish_listing", 1, true, Some(vacancy_start))
+        .expect("second subtask checks off");
+    let publish = instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_publish_listing")
+        .expect("publish listing task present");
+    assert_eq!(publish.status, TaskStatus::InProgress);
+    assert_eq!(publish.subtasks().len(), 3);
+    assert_eq!(
+        readiness(&instance),
+        ((2.0 / 3.0) / task_count * 100.0).round() as u8
+    );
+
+    let finished_on = vacancy_start + Duration::days(1);
+    instance
+        .set_subtask("marketing_publish_listing", 2, true, Some(finished_on))
+        .expect("last subtask checks off");
+    let publish = instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_publish_listing")
+        .expect("publish listing task present");
+    assert_eq!(publish.status, TaskStatus::Completed);
+    assert_eq!(publish.completed_on, Some(finished_on));
+    assert_eq!(
+        publish
+            .history()
+            .last()
+            .and_then(|event| event.note.as_deref()),
+        Some("All required subtasks complete")
+    );
+
+    instance
+        .set_subtask("marketing_publish_listing", 2, false, None)
+        .expect("subtask reopens");
+    let publish = instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_publish_listing")
+        .expect("publish listing task present");
+    assert_eq!(publish.status, TaskStatus::InProgress);
+    assert_eq!(publish.completed_on, None);
+
+    match instance.set_subtask("marketing_publish_listing", 7, true, None) {
+        Err(VacancyError::SubtaskNotFound { index, .. }) => assert_eq!(index, 7),
+        other => panic!("expected missing subtask error, got {other:?}"),
+    }
+    match instance.set_subtask("leasing_collect_funds", 0, true, None) {
+        Err(VacancyError::PrerequisitesIncomplete { .. }) => {}
+        other => panic!("expected prerequisites error, got {other:?}"),
+    }
+}
+
+#[test]
+fn completing_a_task_checks_off_its_subtasks() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+
+    instance
+        .set_status(
+            "marketing_update_appfolio",
+            TaskStatus::Completed,
+            Some(vacancy_start),
+        )
+        .expect("appfolio update has no prerequisites");
+    let task = instance
+        .tasks()
+        .iter()
+        .find(|task| task.template.key == "marketing_update_appfolio")
+        .expect("appfolio task present");
+    assert!(task
+        .subtasks()
+        .iter()
+        .all(|subtask| subtask.completed && subtask.completed_on == Some(vacancy_start)));
+}
+
+#[test]
+fn reopening_a_task_unchecks_subtasks_its_completion_checked() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+    let publish = |instance: &VacancyWorkflowInstance| {
+        instance
+            .tasks()
+            .iter()
+            .find(|task| task.template.key == "marketing_publish_listing")
+            .expect("publish listing present")
+            .clone()
+    };
+
+    instance
+        .set_subtask("marketing_publish_listing", 0, true, Some(vacancy_start))
+        .expect("first subtask checks off");
+    instance
+        .set_status(
+            "marketing_publish_listing",
+            TaskStatus::Completed,
+            Some(vacancy_start),
+        )
+        .expect("publish listing has no prerequisites");
+    assert_eq!(publish(&instance).progress(), 1.0);
+
+    instance
+        .set_status("marketing_publish_listing", TaskStatus::InProgress, None)
+        .expect("task reopens");
+    let reopened = publish(&instance);
+    assert!(reopened.progress() < 1.0);
+    let checked: Vec<bool> = reopened
+        .subtasks()
+        .iter()
+        .map(|subtask| subtask.completed)
+        .collect();
+    assert_eq!(checked, vec![true, false, false]);
+    assert!(reopened.subtasks()[1..]
+        .iter()
+        .all(|subtask| subtask.completed_on.is_none()));
+
+    instance
+        .set_subtask("marketing_publish_listing", 1, true, Some(vacancy_start))
+        .expect("second subtask checks off");
+    assert_eq!(publish(&instance).status, TaskStatus::InProgress);
+}
+
+#[test]
+fn compliance_evidence_downgrades_and_escalates_alerts() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+    let topic = "Iowa Code § 562A.29 reasonable re-rental efforts";
+    let alerts_for = |instance: &VacancyWorkflowInstance| {
+        instance
+            .report(vacancy_start)
+            .compliance_alerts
+            .into_iter()
+            .filter(|alert| alert.topic == topic)
+            .map(|alert| alert.severity)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(alerts_for(&instance), vec![ComplianceSeverity::Warning]);
+
+    instance
+        .set_status(
+            "marketing_publish_listing",
+            TaskStatus::Completed,
+            Some(vacancy_start),
+        )
+        .expect("listing has no prerequisites");
+    assert_eq!(
+        alerts_for(&instance),
+        vec![ComplianceSeverity::Critical],
+        "completed without required evidence escalates"
+    );
+
+    let evidence = ComplianceEvidence {
+        topic: topic.to_string(),
+        document: DocumentDescriptor {
+            name: "Syndication log".to_string(),
+            category: DocumentCategory::Misc,
+            storage_key: "vacancies/syndication.pdf".to_string(),
+        },
+        url: None,
+        captured_at: Utc.with_ymd_and_hms(2025, 9, 24, 15, 0, 0).unwrap(),
+    };
+    instance
+        .attach_evidence("marketing_publish_listing", evidence.clone())
+        .expect("note exists");
+    assert!(alerts_for(&instance).is_empty());
+
+    let mut open = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+    open.attach_evidence("marketing_publish_listing", evidence.clone())
+        .expect("note exists");
+    assert_eq!(
+        alerts_for(&open),
+        vec![ComplianceSeverity::Info],
+        "documented warning drops to info"
+    );
+
+    let wrong_topic = ComplianceEvidence {
+        topic: "Unrelated".to_string(),
+        ..evidence
+    };
+    match open.attach_evidence("marketing_publish_listing", wrong_topic) {
+        Err(VacancyError::UnknownComplianceNote { topic, .. }) => assert_eq!(topic, "Unrelated"),
+        other => panic!("expected unknown note error, got {other:?}"),
+    }
+}
+
+#[test]
+fn severity_policy_grades_alerts_by_due_window() {
+    let blueprint = VacancyWorkflowBlueprint::standard();
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let mut instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+
+    let severity_of = |instance: &VacancyWorkflowInstance, task_key: &str, today: NaiveDate| {
+        instance
+            .report(today)
+            .compliance_alerts
+            .into_iter()
+            .find(|alert| alert.task_key == task_key)
+            .map(|alert| alert.severity)
+    };
+
+    assert_eq!(
+        severity_of(&instance, "leasing_lihtc_certification", vacancy_start),
+        Some(ComplianceSeverity::Info),
+        "far-off tasks stay informational on day zero"
+    );
+    assert_eq!(
+        severity_of(&instance, "marketing_publish_listing", vacancy_start),
+        Some(ComplianceSeverity::Warning)
+    );
+    assert_eq!(
+        severity_of(
+            &instance,
+            "marketing_publish_listing",
+            vacancy_start + Duration::days(2)
+        ),
+        Some(ComplianceSeverity::Critical)
+    );
+
+    instance.set_severity_policy(SeverityPolicy {
+        warning_window_days: 30,
+    });
+    assert_eq!(
+        severity_of(&instance, "leasing_lihtc_certification", vacancy_start),
+        Some(ComplianceSeverity::Warning)
+    );
+}
+
+#[test]
+fn per_note_overrides_mark_statutory_and_custom_windows() {
+    let toml = r#"
+[[tasks]]
+key = "marketing_publish_listing"
+name = "Create and Publish Listing"
+stage = "marketing_and_advertising"
+role = "leasing_agent"
+due = { days_from_vacancy = 10 }
+
+[[tasks.compliance]]
+topic = "Reasonable re-rental efforts"
+detail = "Document every marketing channel touch."
+statutory = true
+
+[[tasks.compliance]]
+topic = "Listing accuracy"
+detail = "Confirm the rent and ready date."
+warning_window_days = 14
+"#;
+    let blueprint = VacancyWorkflowBlueprint::from_reader(
+        std::io::Cursor::new(toml),
+        tenant_ai::workflows::vacancy::BlueprintFormat::Toml,
+    )
+    .expect("blueprint loads");
+    let (vacancy_start, target_move_in) = vacancy_dates();
+    let instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
+
+    let severities: Vec<(String, ComplianceSeverity)> = instance
+        .report(vacancy_start)
+        .compliance_alerts
+        .into_iter()
+        .map(|alert| (alert.topic, alert.severity))
+        .collect();
+    assert_eq!(
+        severities,
+        vec![
+            (
+                "Reasonable re-rental efforts".to_string(),
+                ComplianceSeverity::Critical
+            ),
+            ("Listing accuracy".to_string(), ComplianceSeverity::Warning),
+        ]
+    );
+}
@@ -1,6 +1,10 @@
-use crate::demo::{run_demo, run_vacancy_report, DemoArgs, VacancyReportArgs};
+use crate::demo::{
+    run_demo, run_portfolio_report, run_readiness_snapshot, run_vacancy_report, DemoArgs,
+    PortfolioReportArgs, SnapshotArgs, VacancyReportArgs,
+};
 use crate::server;
 use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
 use tenant_ai::error::AppError;
 
 #[derive(Parser, Debug)]
@@ -31,6 +35,10 @@ enum Command {
 enum VacancyCommand {
     /// Generate a vacancy workflow report and optional task listing
     Report(VacancyReportArgs),
+    /// Roll up every tracked vacancy in a store into a portfolio report
+    Portfolio(PortfolioReportArgs),
+    /// Record today's readiness for every tracked vacancy and print each trend
+    Snapshot(SnapshotArgs),
 }
 
 #[derive(Args, Debug, Default)]
@@ -41,6 +49,9 @@ pub(crate) struct ServeArgs {
     /// Override the configured port for the HTTP server
     #[arg(long)]
     pub(crate) port: Option<u16>,
+    /// Persist tracked vacancies to this JSON file instead of keeping them in memory
+    #[arg(long)]
+    pub(crate) vacancy_store: Option<PathBuf>,
 }
 
 pub(crate) async fn run() -> Result<(), AppError> {
@@ -54,6 +65,12 @@ pub(crate) async fn run() -> Result<(), AppError> {
         Command::Vacancy {
             command: VacancyCommand::Report(args),
         } => run_vacancy_report(args),
+        Command::Vacancy {
+            command: VacancyCommand::Portfolio(args),
+        } => run_portfolio_report(args),
+        Command::Vacancy {
+            command: VacancyCommand::Snapshot(args),
+        } => run_readiness_snapshot(args),
         Command::Demo(args) => run_demo(args),
     }
 }
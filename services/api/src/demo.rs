@@ -6,7 +6,10 @@ use clap::Args;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tenant_ai::error::AppError;
-use tenant_ai::workflows::apollo::ApolloVacancyImporter;
+use tenant_ai::workflows::apollo::{
+    ApolloImportDiagnostics, ApolloImportOptions, ApolloNameMap, ApolloVacancyImporter,
+    DuplicateRowPolicy,
+};
 use tenant_ai::workflows::vacancy::applications::{
     ApplicationRepository, ApplicationSubmission, CriminalClassification, CriminalRecord,
     DocumentCategory, DocumentDescriptor, EvaluationConfig, HouseholdComposition,
@@ -14,7 +17,11 @@ use tenant_ai::workflows::vacancy::applications::{
     SubsidyProgram, VacancyApplicationService, VacancyListingSnapshot,
 };
 use tenant_ai::workflows::vacancy::{
-    VacancyReport, VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+    domain::SeverityPolicy, portfolio_report, report::ReadinessModel, report::ReadinessTrend,
+    report::DEFAULT_PORTFOLIO_OVERDUE_LIMIT, AssignmentPolicy, AutomationTrigger,
+    BlueprintRegistry, BusinessCalendar, DurationHistory, FileVacancyRepository, ForecastOptions,
+    PortfolioReport, StaffAssigner, StaffRoster, StaffingReport, VacancyReport,
+    VacancyWorkflowInstance, VacancyWorkflowRepository,
 };
 
 #[derive(Args, Debug, Default)]
@@ -28,9 +35,15 @@ pub(crate) struct DemoArgs {
     /// Override the reporting date (defaults to today).
     #[arg(long, value_parser = crate::infra::parse_date)]
     pub(crate) today: Option<NaiveDate>,
-    /// Optional Apollo CSV export to hydrate the vacancy report.
+    /// Optional Apollo CSV or Asana JSON export to hydrate the vacancy report.
     #[arg(long)]
     pub(crate) apollo_csv: Option<PathBuf>,
+    /// Optional holiday calendar (.csv or .ics) for business-day due dates.
+    #[arg(long)]
+    pub(crate) holiday_calendar: Option<PathBuf>,
+    /// Program blueprint (lihtc, market_rate, section_8, student_housing).
+    #[arg(long, default_value = BlueprintRegistry::DEFAULT_PROGRAM)]
+    pub(crate) blueprint: String,
     /// Include a full task listing in the vacancy portion of the demo output.
     #[arg(long)]
     pub(crate) include_tasks: bool,
@@ -50,47 +63,315 @@ pub(crate) struct VacancyReportArgs {
     /// Evaluation date for the report (defaults to today)
     #[arg(long, value_parser = crate::infra::parse_date)]
     pub(crate) today: Option<NaiveDate>,
-    /// Optional Apollo CSV export to hydrate task progress
+    /// Optional Apollo CSV or Asana JSON export to hydrate task progress
     #[arg(long)]
     pub(crate) apollo_csv: Option<PathBuf>,
+    /// Minimum confidence (0-1) for applying a fuzzy Apollo task name match
+    #[arg(long, default_value_t = ApolloImportOptions::DEFAULT_MATCH_THRESHOLD)]
+    pub(crate) match_threshold: f32,
+    /// Extra Apollo task names (.csv with name,task_key or .toml) merged over the built-in map
+    #[arg(long)]
+    pub(crate) apollo_name_map: Option<PathBuf>,
+    /// Which Apollo row wins when several map to one task (latest_modified, prefer_completed, or report_conflicts)
+    #[arg(long, value_parser = crate::infra::parse_duplicate_policy, default_value = "latest_modified")]
+    pub(crate) duplicate_policy: DuplicateRowPolicy,
+    /// Optional holiday calendar (.csv or .ics) for business-day due dates
+    #[arg(long)]
+    pub(crate) holiday_calendar: Option<PathBuf>,
+    /// Program blueprint (lihtc, market_rate, section_8, student_housing)
+    #[arg(long, default_value = BlueprintRegistry::DEFAULT_PROGRAM)]
+    pub(crate) blueprint: String,
+    /// Optional staff roster (TOML) used to auto-assign open tasks
+    #[arg(long)]
+    pub(crate) staff_roster: Option<PathBuf>,
+    /// How open tasks are spread across the roster (round_robin or least_loaded)
+    #[arg(long, value_parser = crate::infra::parse_assignment_policy, default_value = "least_loaded")]
+    pub(crate) assignment_policy: AssignmentPolicy,
+    /// Days before a due date that compliance notes escalate from Info to Warning
+    #[arg(long, default_value_t = SeverityPolicy::DEFAULT_WARNING_WINDOW_DAYS)]
+    pub(crate) warning_window_days: u32,
+    /// Readiness scoring model (task_completion or criticality)
+    #[arg(long, value_parser = crate::infra::parse_readiness_model, default_value = "task_completion")]
+    pub(crate) readiness_model: ReadinessModel,
+    /// Monthly rent (whole dollars) used to price days on market as vacancy loss
+    #[arg(long)]
+    pub(crate) monthly_rent: Option<u32>,
+    /// Past Apollo CSV export used to learn task durations for the forecast (repeatable)
+    #[arg(long)]
+    pub(crate) history_csv: Vec<PathBuf>,
     /// Include a full task listing in the output
     #[arg(long)]
     pub(crate) list_tasks: bool,
 }
 
+#[derive(Args, Debug)]
+pub(crate) struct PortfolioReportArgs {
+    /// Vacancy store written by `serve --vacancy-store`
+    #[arg(long)]
+    pub(crate) store: PathBuf,
+    /// Limit the rollup to a single property code
+    #[arg(long)]
+    pub(crate) property: Option<String>,
+    /// Evaluation date for the report (defaults to today)
+    #[arg(long, value_parser = crate::infra::parse_date)]
+    pub(crate) today: Option<NaiveDate>,
+    /// Number of overdue tasks to list portfolio-wide
+    #[arg(long, default_value_t = DEFAULT_PORTFOLIO_OVERDUE_LIMIT)]
+    pub(crate) overdue_limit: usize,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct SnapshotArgs {
+    /// Vacancy store written by `serve --vacancy-store`
+    #[arg(long)]
+    pub(crate) store: PathBuf,
+    /// Limit snapshots to a single property code
+    #[arg(long)]
+    pub(crate) property: Option<String>,
+    /// Snapshot date (defaults to today); re-running on the same date replaces it
+    #[arg(long, value_parser = crate::infra::parse_date)]
+    pub(crate) today: Option<NaiveDate>,
+}
+
 pub(crate) fn run_vacancy_report(args: VacancyReportArgs) -> Result<(), AppError> {
     let VacancyReportArgs {
         vacancy_start,
         target_move_in,
         today,
         apollo_csv,
+        match_threshold,
+        apollo_name_map,
+        duplicate_policy,
+        holiday_calendar,
+        blueprint,
+        staff_roster,
+        assignment_policy,
+        warning_window_days,
+        readiness_model,
+        monthly_rent,
+        history_csv,
         list_tasks,
     } = args;
 
     let today = today.unwrap_or_else(|| Local::now().date_naive());
-    let (instance, imported) =
-        load_vacancy_instance_from_path(apollo_csv, vacancy_start, target_move_in)?;
+    let apollo_options = ApolloImportOptions {
+        match_threshold,
+        name_map: match apollo_name_map {
+            Some(path) => ApolloNameMap::from_path(path)?,
+            None => ApolloNameMap::default(),
+        },
+        configured_name_map: ApolloNameMap::default(),
+        duplicate_policy,
+    };
+    let (mut instance, import_diagnostics) = load_vacancy_instance_from_path(
+        apollo_csv,
+        holiday_calendar,
+        &blueprint,
+        &apollo_options,
+        vacancy_start,
+        target_move_in,
+    )?;
+    instance.set_severity_policy(SeverityPolicy {
+        warning_window_days,
+    });
+    instance.set_monthly_rent(monthly_rent);
+
+    let mut history = DurationHistory::new();
+    let history_blueprint = BlueprintRegistry::shared_builtin().select(Some(&blueprint))?;
+    for path in history_csv {
+        history.merge(ApolloVacancyImporter::duration_history_with_options(
+            std::fs::File::open(path)?,
+            history_blueprint,
+            instance.calendar(),
+            &apollo_options,
+        )?);
+    }
+
+    let staffing = match staff_roster {
+        Some(path) => {
+            let roster = StaffRoster::from_path(path)?;
+            StaffAssigner::new(&roster, assignment_policy).assign(&mut instance);
+            Some(instance.staffing(&roster, today))
+        }
+        None => None,
+    };
 
     let report = instance.report(today);
     render_vacancy_report(
         &instance,
         &report,
-        vacancy_start,
-        target_move_in,
         today,
-        imported,
-        list_tasks,
+        &RenderOptions {
+            blueprint: &blueprint,
+            import_diagnostics: import_diagnostics.as_ref(),
+            staffing: staffing.as_ref(),
+            readiness_model,
+            history: &history,
+            list_tasks,
+        },
     );
 
     Ok(())
 }
 
+pub(crate) fn run_portfolio_report(args: PortfolioReportArgs) -> Result<(), AppError> {
+    let PortfolioReportArgs {
+        store,
+        property,
+        today,
+        overdue_limit,
+    } = args;
+
+    let repository = FileVacancyRepository::open(store)?;
+    let records = match property.as_deref() {
+        Some(property_code) => repository.list_by_property(property_code)?,
+        None => repository.list_all()?,
+    };
+    let today = today.unwrap_or_else(|| Local::now().date_naive());
+
+    render_portfolio_report(&portfolio_report(&records, today, overdue_limit));
+    Ok(())
+}
+
+pub(crate) fn run_readiness_snapshot(args: SnapshotArgs) -> Result<(), AppError> {
+    let SnapshotArgs {
+        store,
+        property,
+        today,
+    } = args;
+
+    let repository = FileVacancyRepository::open(store)?;
+    let records = match property.as_deref() {
+        Some(property_code) => repository.list_by_property(property_code)?,
+        None => repository.list_all()?,
+    };
+    let today = today.unwrap_or_else(|| Local::now().date_naive());
+
+    println!(
+        "Recorded readiness snapshots for {} vacancies ({})",
+        records.len(),
+        today
+    );
+    for mut record in records {
+        record.instance.record_snapshot(today);
+        println!("\n{}", record.id);
+        render_readiness_trend(&record.instance.readiness_trend());
+        repository.update(record)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn render_readiness_trend(trend: &ReadinessTrend) {
+    println!("Readiness trend ({} snapshot(s))", trend.points.len());
+    for point in &trend.points {
+        println!(
+            "- {}: {}% ({}), {} open, {} overdue, {} critical alert(s)",
+            point.date,
+            point.readiness_score,
+            point.readiness_level.label(),
+            point.open_tasks,
+            point.overdue_tasks,
+            point.critical_alerts
+        );
+    }
+    for transition in &trend.level_transitions {
+        println!(
+            "Level change {}: {} -> {}",
+            transition.date,
+            transition.from.label(),
+            transition.to.label()
+        );
+    }
+    if trend.points.len() > 1 {
+        println!("Net change: {:+} point(s)", trend.score_change);
+    }
+}
+
+pub(crate) fn render_portfolio_report(portfolio: &PortfolioReport) {
+    println!("Vacancy portfolio rollup");
+    println!(
+        "{} open vacancies (evaluated {})",
+        portfolio.open_vacancies, portfolio.today
+    );
+    println!(
+        "Average days on market: {:.1}",
+        portfolio.average_days_on_market
+    );
+    let loss = &portfolio.vacancy_loss;
+    if loss.units_with_rent > 0 {
+        println!(
+            "Vacancy loss across {} priced unit(s): ${:.2}/day | ${:.2} incurred | ${:.2} projected",
+            loss.units_with_rent, loss.daily_loss, loss.incurred_loss, loss.projected_loss
+        );
+    }
+
+    println!("\nStage progress");
+    for progress in &portfolio.stage_progress {
+        println!(
+            "- {}: {}/{} tasks completed",
+            progress.stage_label, progress.completed, progress.total
+        );
+    }
+
+    println!("\nRole workload");
+    for load in &portfolio.role_load {
+        println!(
+            "- {}: {} open, {} overdue",
+            load.role_label, load.open, load.overdue
+        );
+    }
+
+    println!("\nUnits by readiness");
+    for unit in &portfolio.units {
+        println!(
+            "{}. {} - {}% readiness ({}), {} day(s) on market, {} day(s) to move-in, {} overdue",
+            unit.rank,
+            unit.vacancy_id,
+            unit.readiness_score,
+            unit.readiness_label,
+            unit.days_on_market,
+            unit.days_until_move_in,
+            unit.overdue_tasks
+        );
+    }
+
+    println!("\nAt-risk units by property");
+    for property in &portfolio.properties {
+        println!(
+            "- {}: {} of {} open vacancies at risk",
+            property.property_code, property.at_risk, property.open_vacancies
+        );
+    }
+
+    if portfolio.top_overdue_tasks.is_empty() {
+        println!("\nTop overdue tasks: none");
+    } else {
+        println!("\nTop overdue tasks");
+        for overdue in &portfolio.top_overdue_tasks {
+            println!(
+                "- {} {} ({}), due {}, {} day(s) overdue",
+                overdue.vacancy_id,
+                overdue.task.name,
+                overdue.task.role_label,
+                overdue.task.due_date,
+                overdue.days_overdue
+            );
+        }
+    }
+}
+
+/// Rent advertised for the demo unit, shared by the vacancy and application segments.
+const DEMO_LISTED_RENT: u32 = 1180;
+
 pub(crate) fn run_demo(args: DemoArgs) -> Result<(), AppError> {
     let DemoArgs {
         vacancy_start,
         target_move_in,
         today,
         apollo_csv,
+        holiday_calendar,
+        blueprint,
         include_tasks,
         skip_application,
     } = args;
@@ -101,17 +382,28 @@ pub(crate) fn run_demo(args: DemoArgs) -> Result<(), AppError> {
     let today = today.unwrap_or_else(|| Local::now().date_naive());
 
     println!("Agentic workflow demo");
-    let (instance, imported) =
-        load_vacancy_instance_from_path(apollo_csv, vacancy_start, target_move_in)?;
+    let (mut instance, import_diagnostics) = load_vacancy_instance_from_path(
+        apollo_csv,
+        holiday_calendar,
+        &blueprint,
+        &ApolloImportOptions::default(),
+        vacancy_start,
+        target_move_in,
+    )?;
+    instance.set_monthly_rent(Some(DEMO_LISTED_RENT));
     let report = instance.report(today);
     render_vacancy_report(
         &instance,
         &report,
-        vacancy_start,
-        target_move_in,
         today,
-        imported,
-        include_tasks,
+        &RenderOptions {
+            blueprint: &blueprint,
+            import_diagnostics: import_diagnostics.as_ref(),
+            staffing: None,
+            readiness_model: ReadinessModel::default(),
+            history: &DurationHistory::new(),
+            list_tasks: include_tasks,
+        },
     );
 
     let lead_summary = synthetic_lead_automation_summary(today, target_move_in);
@@ -246,7 +538,7 @@ fn demo_application_submission(
     target_move_in: NaiveDate,
     config: &EvaluationConfig,
 ) -> ApplicationSubmission {
-    let listed_rent = 1180;
+    let listed_rent = DEMO_LISTED_RENT;
     let deposit_cap = ((listed_rent as f32) * config.deposit_cap_multiplier)
         .ceil()
         .clamp(0.0, u32::MAX as f32) as u32;
@@ -308,44 +600,171 @@ fn demo_application_submission(
 
 pub(crate) fn load_vacancy_instance_from_path(
     apollo_csv: Option<PathBuf>,
+    holiday_calendar: Option<PathBuf>,
+    blueprint: &str,
+    apollo_options: &ApolloImportOptions,
     vacancy_start: NaiveDate,
     target_move_in: NaiveDate,
-) -> Result<(VacancyWorkflowInstance, bool), AppError> {
-    match apollo_csv {
-        Some(path) => ApolloVacancyImporter::from_path(path, vacancy_start, target_move_in)
-            .map(|instance| (instance, true))
-            .map_err(AppError::from),
+) -> Result<(VacancyWorkflowInstance, Option<ApolloImportDiagnostics>), AppError> {
+    let registry = BlueprintRegistry::shared_builtin();
+    let blueprint = registry.select(Some(blueprint))?;
+    let (mut instance, import_diagnostics) = match apollo_csv {
+        Some(path) => {
+            let file = std::fs::File::open(path)?;
+            let import = ApolloVacancyImporter::import_with_options(
+                file,
+                blueprint,
+                vacancy_start,
+                target_move_in,
+                apollo_options,
+            )?;
+            (import.instance, Some(import.diagnostics))
+        }
         None => {
-            let blueprint = VacancyWorkflowBlueprint::standard();
-            let instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
-            Ok((instance, false))
+            let instance = VacancyWorkflowInstance::new(blueprint, vacancy_start, target_move_in);
+            (instance, None)
         }
+    };
+
+    if let Some(path) = holiday_calendar {
+        instance.set_calendar(BusinessCalendar::from_path(path)?);
     }
+
+    Ok((instance, import_diagnostics))
+}
+
+/// Unmapped rows listed before the rest are summarised as a count.
+const IMPORT_DIAGNOSTIC_LIMIT: usize = 10;
+
+pub(crate) fn render_import_diagnostics(diagnostics: &ApolloImportDiagnostics) {
+    if diagnostics.is_clean() {
+        println!(
+            "Import diagnostics: all {} row(s) applied",
+            diagnostics.rows_read
+        );
+        return;
+    }
+
+    println!(
+        "Import diagnostics: {} row(s) read, {} unmapped, {} fuzzy matched, {} duplicate, {} conflicting, {} outside blueprint, {} invalid date(s), {} task(s) missing",
+        diagnostics.rows_read,
+        diagnostics.unmapped_rows.len(),
+        diagnostics.fuzzy_matches.len(),
+        diagnostics.duplicate_rows.len(),
+        diagnostics.conflicting_rows.len(),
+        diagnostics.excluded_rows.len(),
+        diagnostics.invalid_dates.len(),
+        diagnostics.missing_tasks.len()
+    );
+    for unmapped in diagnostics
+        .unmapped_rows
+        .iter()
+        .take(IMPORT_DIAGNOSTIC_LIMIT)
+    {
+        println!("- Row {}: unmapped \"{}\"", unmapped.row, unmapped.name);
+    }
+    if diagnostics.unmapped_rows.len() > IMPORT_DIAGNOSTIC_LIMIT {
+        println!(
+            "- ... and {} more unmapped row(s)",
+            diagnostics.unmapped_rows.len() - IMPORT_DIAGNOSTIC_LIMIT
+        );
+    }
+    for matched in &diagnostics.fuzzy_matches {
+        println!(
+            "- Row {}: \"{}\" fuzzy matched to {} (confidence {:.2})",
+            matched.row, matched.name, matched.task_key, matched.confidence
+        );
+    }
+    for duplicate in &diagnostics.duplicate_rows {
+        println!(
+            "- Row {}: duplicate of row {} for {}",
+            duplicate.row, duplicate.applied_row, duplicate.task_key
+        );
+    }
+    for conflict in &diagnostics.conflicting_rows {
+        let rows: Vec<String> = conflict.rows.iter().map(usize::to_string).collect();
+        println!(
+            "- Rows {}: conflicting status for {}, left unchanged",
+            rows.join(", "),
+            conflict.task_key
+        );
+    }
+    for excluded in &diagnostics.excluded_rows {
+        println!(
+            "- Row {}: {} is not part of this blueprint",
+            excluded.row, excluded.task_key
+        );
+    }
+    for invalid in &diagnostics.invalid_dates {
+        println!(
+            "- Row {}: unreadable {} \"{}\"",
+            invalid.row, invalid.column, invalid.value
+        );
+    }
+    for missing in &diagnostics.missing_tasks {
+        println!(
+            "- Missing from export: {} ({})",
+            missing.name, missing.task_key
+        );
+    }
+}
+
+/// What `render_vacancy_report` prints beyond the report itself.
+pub(crate) struct RenderOptions<'a> {
+    pub(crate) blueprint: &'a str,
+    /// Present when the vacancy came from an Apollo export.
+    pub(crate) import_diagnostics: Option<&'a ApolloImportDiagnostics>,
+    pub(crate) staffing: Option<&'a StaffingReport>,
+    pub(crate) readiness_model: ReadinessModel,
+    /// Task durations from past exports; empty means the forecast uses blueprint durations.
+    pub(crate) history: &'a DurationHistory,
+    pub(crate) list_tasks: bool,
 }
 
 pub(crate) fn render_vacancy_report(
     instance: &VacancyWorkflowInstance,
     report: &VacancyReport,
-    vacancy_start: NaiveDate,
-    target_move_in: NaiveDate,
     today: NaiveDate,
-    imported: bool,
-    list_tasks: bool,
+    options: &RenderOptions<'_>,
 ) {
+    let RenderOptions {
+        blueprint,
+        import_diagnostics,
+        staffing,
+        readiness_model,
+        history,
+        list_tasks,
+    } = *options;
+    let vacancy_start = instance.vacancy_start();
+    let target_move_in = instance.target_move_in();
+
     println!("Vacancy workflow demo");
     println!(
         "Vacancy window: {} -> {} (evaluated {})",
         vacancy_start, target_move_in, today
     );
 
-    if imported {
+    println!("Blueprint: {}", blueprint);
+    if let Some(diagnostics) = import_diagnostics {
         println!("Data source: Apollo CSV import");
+        render_import_diagnostics(diagnostics);
     } else {
-        println!("Data source: Standard blueprint (no Apollo data provided)");
+        println!("Data source: Blueprint defaults (no Apollo data provided)");
     }
 
     let summary = report.summary();
-    let insights = summary.insights(instance, vacancy_start, target_move_in, today);
+    let insights = summary.insights_with_scorer(
+        instance,
+        vacancy_start,
+        target_move_in,
+        today,
+        readiness_model.scorer().as_ref(),
+    );
+    let forecast = summary.forecast(instance, today, history, &ForecastOptions::default());
+
+    for change in &summary.schedule_changes {
+        println!("Rescheduled: {}", change.summary());
+    }
 
     println!("\nStage progress");
     for progress in &summary.stage_progress {
@@ -363,6 +782,48 @@ pub(crate) fn render_vacancy_report(
         );
     }
 
+    if let Some(staffing) = staffing {
+        println!("\nStaff workload");
+        for load in &staffing.staff_load {
+            println!(
+                "- {} ({}): {} open, {} overdue",
+                load.name, load.staff_id, load.open, load.overdue
+            );
+        }
+        if staffing.unassigned_open > 0 {
+            println!(
+                "- Unassigned: {} open task(s) with no assignee",
+                staffing.unassigned_open
+            );
+        }
+        for unknown in &staffing.unknown_assignees {
+            println!(
+                "- {} (not on roster): {} open, {} overdue",
+                unknown.assignee, unknown.open, unknown.overdue
+            );
+        }
+        if !staffing.reassignment_suggestions.is_empty() {
+            println!("\nSuggested reassignments");
+            for suggestion in &staffing.reassignment_suggestions {
+                println!(
+                    "- {}: {} -> {} ({})",
+                    suggestion.task_name,
+                    suggestion.from_staff_id,
+                    suggestion.to_staff_id,
+                    suggestion.reason
+                );
+            }
+        }
+    } else if !summary.staff_load.is_empty() {
+        println!("\nStaff workload");
+        for load in &summary.staff_load {
+            println!(
+                "- {}: {} open, {} overdue",
+                load.assignee, load.open, load.overdue
+            );
+        }
+    }
+
     if summary.overdue_tasks.is_empty() {
         println!("\nOverdue tasks: none");
     } else {
@@ -387,6 +848,30 @@ pub(crate) fn render_vacancy_report(
         }
     }
 
+    if summary.actionable_tasks.is_empty() {
+        println!("\nActionable now: none");
+    } else {
+        println!("\nActionable now");
+        for task in &summary.actionable_tasks {
+            println!(
+                "- {} ({}), due {}, status {}",
+                task.name, task.role_label, task.due_date, task.status_label
+            );
+        }
+    }
+
+    if !summary.waiting_tasks.is_empty() {
+        println!("\nWaiting on upstream work");
+        for task in &summary.waiting_tasks {
+            println!(
+                "- {} ({}), waiting on {}",
+                task.name,
+                task.role_label,
+                task.waiting_on.join(", ")
+            );
+        }
+    }
+
     println!(
         "\nReadiness score: {}% ({})",
         insights.readiness_score,
@@ -407,6 +892,48 @@ pub(crate) fn render_vacancy_report(
         }
     }
 
+    if let Some(slack) = insights.schedule_slack_days {
+        println!("Schedule slack: {} day(s) before move-in slips", slack);
+    }
+
+    if let Some(loss) = &insights.vacancy_loss {
+        println!(
+            "Vacancy loss: ${:.2}/day on ${} rent | ${:.2} incurred over {} day(s) on market | ${:.2} projected to move-in (${:.2} remaining)",
+            loss.daily_loss,
+            loss.monthly_rent,
+            loss.incurred_loss,
+            loss.days_on_market,
+            loss.projected_loss,
+            loss.remaining_loss
+        );
+    }
+
+    println!(
+        "Move-in ready forecast: P50 {} | P90 {} | {:.0}% chance by {}",
+        forecast.move_in_ready_p50,
+        forecast.move_in_ready_p90,
+        forecast.on_time_probability * 100.0,
+        forecast.target_move_in
+    );
+
+    if !insights.critical_path.is_empty() {
+        println!("\nCritical path");
+        for step in &insights.critical_path {
+            println!(
+                "- {} ({}), start by {}, finish by {}, slack {} day(s)",
+                step.name, step.role_label, step.latest_start, step.latest_finish, step.slack_days
+            );
+        }
+    }
+
+    println!("\nStage forecast ({} runs)", forecast.runs);
+    for stage in &forecast.stages {
+        println!(
+            "- {}: P50 {}, P90 {}",
+            stage.stage_label, stage.p50, stage.p90
+        );
+    }
+
     if !insights.ai_observations.is_empty() {
         println!("\nAI observations");
         for note in &insights.ai_observations {
@@ -424,7 +951,23 @@ pub(crate) fn render_vacancy_report(
     if !insights.automation_triggers.is_empty() {
         println!("\nAutomation triggers");
         for trigger in &insights.automation_triggers {
-            println!("- {}", trigger);
+            let task_keys = trigger.task_keys().join(", ");
+            match trigger {
+                AutomationTrigger::RemindRole { role, .. } => {
+                    println!("- Remind {} owners: {}", role.label(), task_keys)
+                }
+                AutomationTrigger::EscalateToCoordinator { topics, .. } => println!(
+                    "- Escalate to compliance coordinator: {} ({})",
+                    topics.join("; "),
+                    task_keys
+                ),
+                AutomationTrigger::DispatchAppFolioAlert { .. } => {
+                    println!("- Dispatch overdue tasks to AppFolio: {}", task_keys)
+                }
+            }
+            if !trigger.recipients().is_empty() {
+                println!("  recipients: {}", trigger.recipients().join(", "));
+            }
         }
     }
 
@@ -435,6 +978,11 @@ pub(crate) fn render_vacancy_report(
         }
     }
 
+    if !instance.snapshots().is_empty() {
+        println!();
+        render_readiness_trend(&instance.readiness_trend());
+    }
+
     if list_tasks {
         println!("\nTask breakdown by due date");
         for task in instance.task_details() {
@@ -442,15 +990,56 @@ pub(crate) fn render_vacancy_report(
                 Some(date) => format!(" (completed {date})"),
                 None => String::new(),
             };
+            let assignee_note = match &task.assignee {
+                Some(assignee) => format!(" | {assignee}"),
+                None => String::new(),
+            };
             println!(
-                "- {} | {} | {} | due {} | status {}{}",
+                "- {} | {} | {} | due {} | status {}{}{}",
                 task.key,
                 task.name,
                 task.stage_label,
                 task.due_date,
                 task.status_label,
+                assignee_note,
                 completion_note
             );
+            for subtask in &task.subtasks {
+                let mark = if subtask.completed { "x" } else { " " };
+                let optional = if subtask.required { "" } else { " (optional)" };
+                println!("    [{}] {}{}", mark, subtask.name, optional);
+            }
+            for evidence in &task.evidence {
+                println!(
+                    "    evidence for {}: {} ({}) captured {}",
+                    evidence.topic,
+                    evidence.document.name,
+                    evidence
+                        .url
+                        .as_deref()
+                        .unwrap_or(&evidence.document.storage_key),
+                    evidence.captured_at.format("%Y-%m-%d %H:%M")
+                );
+            }
+            for event in &task.history {
+                let actor = event
+                    .actor
+                    .map(|role| role.label())
+                    .unwrap_or("unattributed");
+                let note = event
+                    .note
+                    .as_deref()
+                    .map(|note| format!(" ({note})"))
+                    .unwrap_or_default();
+                println!(
+                    "    {} {} -> {} by {}{}",
+                    event.recorded_at.format("%Y-%m-%d %H:%M"),
+                    event.from_status.label(),
+                    event.to_status.label(),
+                    actor,
+                    note
+                );
+            }
         }
     }
 }
@@ -4,9 +4,15 @@ use serde::Deserialize;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, Mutex};
+use tenant_ai::workflows::apollo::{ApolloNameMap, DuplicateRowPolicy};
 use tenant_ai::workflows::vacancy::applications::{
     AlertError, AlertPublisher, AppFolioAlert, ApplicationId, ApplicationRecord,
-    ApplicationRepository, EvaluationConfig, RepositoryError, VacancyApplicationStatus,
+    ApplicationRepository, EvaluationConfig, RepositoryError, VacancyAlert,
+    VacancyApplicationStatus,
+};
+use tenant_ai::workflows::vacancy::report::ReadinessModel;
+use tenant_ai::workflows::vacancy::{
+    AssignmentPolicy, AutomationDispatcher, VacancyWorkflowRepository,
 };
 
 #[derive(Clone)]
@@ -15,6 +21,15 @@ pub(crate) struct AppState {
     pub(crate) metrics: Arc<PrometheusHandle>,
 }
 
+/// Vacancy store shared with the HTTP handlers; in-memory or file-backed per `serve` flags.
+pub(crate) type SharedVacancyRepository = Arc<dyn VacancyWorkflowRepository>;
+
+/// Publishes vacancy automation through the same alert publisher as application decisions.
+pub(crate) type SharedAutomationDispatcher = Arc<AutomationDispatcher>;
+
+/// Apollo name map loaded from `APOLLO_NAME_MAP`; request-level entries are layered on top.
+pub(crate) type SharedApolloNameMap = Arc<ApolloNameMap>;
+
 #[derive(Default, Clone)]
 pub(crate) struct InMemoryApplicationRepository {
     records: Arc<Mutex<HashMap<ApplicationId, ApplicationRecord>>>,
@@ -58,6 +73,7 @@ impl ApplicationRepository for InMemoryApplicationRepository {
 #[derive(Default, Clone)]
 pub(crate) struct InMemoryAlertPublisher {
     events: Arc<Mutex<Vec<AppFolioAlert>>>,
+    vacancy_events: Arc<Mutex<Vec<VacancyAlert>>>,
 }
 
 impl AlertPublisher for InMemoryAlertPublisher {
@@ -66,12 +82,26 @@ impl AlertPublisher for InMemoryAlertPublisher {
         guard.push(alert);
         Ok(())
     }
+
+    fn publish_vacancy(&self, alert: VacancyAlert) -> Result<(), AlertError> {
+        let mut guard = self.vacancy_events.lock().expect("alert mutex poisoned");
+        guard.push(alert);
+        Ok(())
+    }
 }
 
 impl InMemoryAlertPublisher {
     pub(crate) fn events(&self) -> Vec<AppFolioAlert> {
         self.events.lock().expect("alert mutex poisoned").clone()
     }
+
+    #[cfg(test)]
+    pub(crate) fn vacancy_events(&self) -> Vec<VacancyAlert> {
+        self.vacancy_events
+            .lock()
+            .expect("alert mutex poisoned")
+            .clone()
+    }
 }
 
 pub(crate) fn default_evaluation_config() -> EvaluationConfig {
@@ -91,6 +121,26 @@ pub(crate) fn parse_date(raw: &str) -> Result<NaiveDate, String> {
         .map_err(|err| format!("failed to parse '{raw}' as YYYY-MM-DD ({err})"))
 }
 
+pub(crate) fn parse_assignment_policy(raw: &str) -> Result<AssignmentPolicy, String> {
+    AssignmentPolicy::from_key(raw).ok_or_else(|| {
+        format!("unknown assignment policy '{raw}' (expected round_robin or least_loaded)")
+    })
+}
+
+pub(crate) fn parse_readiness_model(raw: &str) -> Result<ReadinessModel, String> {
+    ReadinessModel::from_key(raw).ok_or_else(|| {
+        format!("unknown readiness model '{raw}' (expected task_completion or criticality)")
+    })
+}
+
+pub(crate) fn parse_duplicate_policy(raw: &str) -> Result<DuplicateRowPolicy, String> {
+    DuplicateRowPolicy::from_key(raw).ok_or_else(|| {
+        format!(
+            "unknown duplicate policy '{raw}' (expected latest_modified, prefer_completed, or report_conflicts)"
+        )
+    })
+}
+
 pub(crate) fn deserialize_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
 where
     D: serde::Deserializer<'de>,
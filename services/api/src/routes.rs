@@ -1,23 +1,41 @@
-use crate::infra::{deserialize_date, deserialize_optional_date, AppState};
+use crate::infra::{
+    deserialize_date, deserialize_optional_date, AppState, SharedApolloNameMap,
+    SharedAutomationDispatcher, SharedVacancyRepository,
+};
+use axum::extract::Path;
 use axum::http::{header, StatusCode};
 use axum::response::IntoResponse;
 use axum::Extension;
 use axum::Json;
-use chrono::{Local, NaiveDate};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::collections::BTreeMap;
 use std::io::Cursor;
 use std::sync::Arc;
 use tenant_ai::error::AppError;
-use tenant_ai::workflows::apollo::ApolloVacancyImporter;
+use tenant_ai::workflows::apollo::{
+    ApolloImportDiagnostics, ApolloImportOptions, ApolloNameMap, ApolloVacancyImporter,
+    DuplicateRowPolicy,
+};
+use tenant_ai::workflows::vacancy::applications::RepositoryError;
 use tenant_ai::workflows::vacancy::applications::{
-    application_router, AlertPublisher, ApplicationRepository, VacancyApplicationService,
+    application_router, AlertPublisher, ApplicationRepository, DocumentDescriptor,
+    VacancyApplicationService, VacancyListingSnapshot,
 };
 use tenant_ai::workflows::vacancy::{
+    domain::{
+        ComplianceEvidence, ScheduleChange, SeverityPolicy, StatusChange, TaskStatus, VacancyError,
+        VacancyRole,
+    },
+    portfolio_report,
     report::views::{
         ComplianceAlertView, RoleLoadEntry, StageProgressEntry, TaskSnapshotView, VacancyInsights,
     },
-    TaskDetailView, VacancyWorkflowBlueprint, VacancyWorkflowInstance,
+    report::{ReadinessModel, ReadinessSnapshot, ReadinessTrend, DEFAULT_PORTFOLIO_OVERDUE_LIMIT},
+    AssignmentPolicy, BlueprintRegistry, BusinessCalendar, DispatchOutcome, DurationHistory,
+    ForecastOptions, PortfolioReport, StaffAssigner, StaffMember, StaffRoster, StaffingReport,
+    TaskDetailView, VacancyForecast, VacancyId, VacancyRecord, VacancyWorkflowInstance,
 };
 
 #[derive(Debug, Deserialize)]
@@ -32,6 +50,41 @@ pub(crate) struct VacancyReportRequest {
     pub(crate) include_tasks: bool,
     #[serde(default)]
     pub(crate) apollo_csv: Option<String>,
+    #[serde(default)]
+    pub(crate) holidays: Vec<NaiveDate>,
+    #[serde(default)]
+    pub(crate) blueprint: Option<String>,
+    /// Roster used to auto-assign open tasks and report per-person load.
+    #[serde(default)]
+    pub(crate) staff: Vec<StaffMember>,
+    #[serde(default)]
+    pub(crate) assignment_policy: Option<AssignmentPolicy>,
+    /// Days before a task's due date that its compliance notes escalate from Info to Warning.
+    #[serde(default)]
+    pub(crate) warning_window_days: Option<u32>,
+    /// Readiness scoring model: `task_completion` (default) or `criticality`.
+    #[serde(default)]
+    pub(crate) readiness_model: ReadinessModel,
+    /// Monthly rent used to price vacancy loss; overrides `listing.listed_rent`.
+    #[serde(default)]
+    pub(crate) monthly_rent: Option<u32>,
+    #[serde(default)]
+    pub(crate) listing: Option<VacancyListingSnapshot>,
+    /// Past Apollo exports whose completed rows train the move-in forecast.
+    #[serde(default)]
+    pub(crate) history_csv: Vec<String>,
+    /// Minimum confidence (0–1) for applying a fuzzy Apollo name match; defaults to 0.9.
+    #[serde(default)]
+    pub(crate) match_threshold: Option<f32>,
+    /// Apollo task name to task key entries for this request, layered over the server's
+    /// configured name map. Every key must exist in the selected blueprint; configured
+    /// entries for tasks outside it only exclude their rows.
+    #[serde(default)]
+    pub(crate) apollo_name_map: BTreeMap<String, String>,
+    /// Which Apollo row wins when several map to one task: `latest_modified` (default),
+    /// `prefer_completed`, or `report_conflicts`.
+    #[serde(default)]
+    pub(crate) duplicate_policy: DuplicateRowPolicy,
 }
 
 #[derive(Debug, Serialize)]
@@ -40,15 +93,130 @@ pub(crate) struct VacancyReportResponse {
     pub(crate) target_move_in: NaiveDate,
     pub(crate) today: NaiveDate,
     pub(crate) data_source: VacancyDataSource,
+    /// Rows the Apollo import skipped; present when `apollo_csv` was supplied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) import_diagnostics: Option<ApolloImportDiagnostics>,
+    pub(crate) blueprint: String,
     pub(crate) stage_progress: Vec<StageProgressEntry>,
     pub(crate) role_load: Vec<RoleLoadEntry>,
     pub(crate) overdue_tasks: Vec<TaskSnapshotView>,
     pub(crate) compliance_alerts: Vec<ComplianceAlertView>,
+    pub(crate) actionable_tasks: Vec<TaskSnapshotView>,
+    pub(crate) waiting_tasks: Vec<TaskSnapshotView>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) schedule_changes: Vec<ScheduleChange>,
     pub(crate) insights: VacancyInsights,
+    pub(crate) forecast: VacancyForecast,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) staffing: Option<StaffingReport>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) tasks: Option<Vec<TaskDetailView>>,
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct CreateVacancyRequest {
+    pub(crate) property_code: String,
+    pub(crate) unit_id: String,
+    #[serde(deserialize_with = "deserialize_date")]
+    pub(crate) vacancy_start: NaiveDate,
+    #[serde(deserialize_with = "deserialize_date")]
+    pub(crate) target_move_in: NaiveDate,
+    #[serde(default)]
+    pub(crate) blueprint: Option<String>,
+    #[serde(default)]
+    pub(crate) holidays: Vec<NaiveDate>,
+    #[serde(default)]
+    pub(crate) monthly_rent: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct UpdateTaskStatusRequest {
+    pub(crate) status: TaskStatus,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub(crate) completed_on: Option<NaiveDate>,
+    #[serde(default)]
+    pub(crate) actor: Option<VacancyRole>,
+    #[serde(default)]
+    pub(crate) note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct AttachEvidenceRequest {
+    /// Topic of the task's compliance note the evidence satisfies.
+    pub(crate) topic: String,
+    pub(crate) document: DocumentDescriptor,
+    #[serde(default)]
+    pub(crate) url: Option<String>,
+    #[serde(default)]
+    pub(crate) captured_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct UpdateSubtaskRequest {
+    pub(crate) completed: bool,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub(crate) completed_on: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct RescheduleVacancyRequest {
+    #[serde(deserialize_with = "deserialize_date")]
+    pub(crate) vacancy_start: NaiveDate,
+    #[serde(deserialize_with = "deserialize_date")]
+    pub(crate) target_move_in: NaiveDate,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct DispatchAutomationRequest {
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub(crate) today: Option<NaiveDate>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct RecordSnapshotRequest {
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub(crate) today: Option<NaiveDate>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct PortfolioReportRequest {
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub(crate) today: Option<NaiveDate>,
+    #[serde(default)]
+    pub(crate) property_code: Option<String>,
+    #[serde(default)]
+    pub(crate) overdue_limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct VacancyResponse {
+    pub(crate) id: String,
+    pub(crate) property_code: String,
+    pub(crate) unit_id: String,
+    pub(crate) vacancy_start: NaiveDate,
+    pub(crate) target_move_in: NaiveDate,
+    pub(crate) tasks: Vec<TaskDetailView>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) schedule_changes: Vec<ScheduleChange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) monthly_rent: Option<u32>,
+}
+
+impl From<&VacancyRecord> for VacancyResponse {
+    fn from(record: &VacancyRecord) -> Self {
+        Self {
+            id: record.id.to_string(),
+            property_code: record.id.property_code.clone(),
+            unit_id: record.id.unit_id.clone(),
+            vacancy_start: record.instance.vacancy_start(),
+            target_move_in: record.instance.target_move_in(),
+            tasks: record.instance.task_details(),
+            schedule_changes: record.instance.schedule_changes().to_vec(),
+            monthly_rent: record.instance.monthly_rent(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub(crate) enum VacancyDataSource {
@@ -71,6 +239,46 @@ where
             "/api/v1/vacancy/report",
             axum::routing::post(vacancy_report_endpoint),
         )
+        .route(
+            "/api/v1/vacancies",
+            axum::routing::post(create_vacancy_endpoint),
+        )
+        .route(
+            "/api/v1/vacancies/:vacancy_id",
+            axum::routing::get(fetch_vacancy_endpoint),
+        )
+        .route(
+            "/api/v1/vacancies/:vacancy_id/schedule",
+            axum::routing::put(reschedule_vacancy_endpoint),
+        )
+        .route(
+            "/api/v1/vacancies/:vacancy_id/tasks/:task_key",
+            axum::routing::patch(update_task_status_endpoint),
+        )
+        .route(
+            "/api/v1/vacancies/:vacancy_id/tasks/:task_key/subtasks/:index",
+            axum::routing::patch(update_subtask_endpoint),
+        )
+        .route(
+            "/api/v1/vacancies/:vacancy_id/tasks/:task_key/evidence",
+            axum::routing::post(attach_evidence_endpoint),
+        )
+        .route(
+            "/api/v1/vacancies/:vacancy_id/automation",
+            axum::routing::post(dispatch_automation_endpoint),
+        )
+        .route(
+            "/api/v1/vacancies/:vacancy_id/snapshots",
+            axum::routing::post(record_snapshot_endpoint),
+        )
+        .route(
+            "/api/v1/vacancies/:vacancy_id/trend",
+            axum::routing::get(readiness_trend_endpoint),
+        )
+        .route(
+            "/api/v1/portfolio/report",
+            axum::routing::post(portfolio_report_endpoint),
+        )
 }
 
 pub(crate) async fn healthcheck() -> Json<serde_json::Value> {
@@ -103,6 +311,7 @@ pub(crate) async fn metrics_endpoint(Extension(state): Extension<AppState>) -> i
 }
 
 pub(crate) async fn vacancy_report_endpoint(
+    Extension(configured_name_map): Extension<SharedApolloNameMap>,
     Json(payload): Json<VacancyReportRequest>,
 ) -> Result<Json<VacancyReportResponse>, AppError> {
     let VacancyReportRequest {
@@ -111,22 +320,91 @@ pub(crate) async fn vacancy_report_endpoint(
         today,
         include_tasks,
         apollo_csv,
+        holidays,
+        blueprint,
+        staff,
+        assignment_policy,
+        warning_window_days,
+        readiness_model,
+        monthly_rent,
+        listing,
+        history_csv,
+        match_threshold,
+        apollo_name_map,
+        duplicate_policy,
     } = payload;
 
-    let (instance, data_source) = if let Some(csv) = apollo_csv {
+    let registry = BlueprintRegistry::shared_builtin();
+    let blueprint_name =
+        blueprint.unwrap_or_else(|| BlueprintRegistry::DEFAULT_PROGRAM.to_string());
+    let blueprint = registry.select(Some(&blueprint_name))?;
+    let options = ApolloImportOptions {
+        match_threshold: match_threshold.unwrap_or(ApolloImportOptions::DEFAULT_MATCH_THRESHOLD),
+        name_map: apollo_name_map.iter().collect(),
+        configured_name_map: ApolloNameMap::clone(&configured_name_map),
+        duplicate_policy,
+    };
+    let (mut instance, data_source, import_diagnostics) = if let Some(csv) = apollo_csv {
         let reader = Cursor::new(csv.into_bytes());
-        let instance = ApolloVacancyImporter::from_reader(reader, vacancy_start, target_move_in)?;
-        (instance, VacancyDataSource::Apollo)
+        let import = ApolloVacancyImporter::import_with_options(
+            reader,
+            blueprint,
+            vacancy_start,
+            target_move_in,
+            &options,
+        )?;
+        (
+            import.instance,
+            VacancyDataSource::Apollo,
+            Some(import.diagnostics),
+        )
     } else {
-        let blueprint = VacancyWorkflowBlueprint::standard();
-        let instance = VacancyWorkflowInstance::new(&blueprint, vacancy_start, target_move_in);
-        (instance, VacancyDataSource::Standard)
+        let instance = VacancyWorkflowInstance::new(blueprint, vacancy_start, target_move_in);
+        (instance, VacancyDataSource::Standard, None)
     };
 
+    if !holidays.is_empty() {
+        instance.set_calendar(BusinessCalendar::with_holidays(holidays));
+    }
+    if let Some(warning_window_days) = warning_window_days {
+        instance.set_severity_policy(SeverityPolicy {
+            warning_window_days,
+        });
+    }
+    if let Some(listing) = &listing {
+        instance.apply_listing(listing);
+    }
+    if monthly_rent.is_some() {
+        instance.set_monthly_rent(monthly_rent);
+    }
+
     let today = today.unwrap_or_else(|| Local::now().date_naive());
+    let staffing = if staff.is_empty() {
+        None
+    } else {
+        let roster = StaffRoster::new(staff)?;
+        StaffAssigner::new(&roster, assignment_policy.unwrap_or_default()).assign(&mut instance);
+        Some(instance.staffing(&roster, today))
+    };
     let report = instance.report(today);
     let summary = report.summary();
-    let insights = summary.insights(&instance, vacancy_start, target_move_in, today);
+    let insights = summary.insights_with_scorer(
+        &instance,
+        vacancy_start,
+        target_move_in,
+        today,
+        readiness_model.scorer().as_ref(),
+    );
+    let mut history = DurationHistory::new();
+    for csv in history_csv {
+        history.merge(ApolloVacancyImporter::duration_history_with_options(
+            Cursor::new(csv.into_bytes()),
+            blueprint,
+            instance.calendar(),
+            &options,
+        )?);
+    }
+    let forecast = summary.forecast(&instance, today, &history, &ForecastOptions::default());
     let tasks = if include_tasks {
         Some(instance.task_details())
     } else {
@@ -138,15 +416,198 @@ pub(crate) async fn vacancy_report_endpoint(
         target_move_in,
         today,
         data_source,
+        import_diagnostics,
+        blueprint: blueprint_name,
         stage_progress: summary.stage_progress,
         role_load: summary.role_load,
         overdue_tasks: summary.overdue_tasks,
         compliance_alerts: summary.compliance_alerts,
+        actionable_tasks: summary.actionable_tasks,
+        waiting_tasks: summary.waiting_tasks,
+        schedule_changes: summary.schedule_changes,
         insights,
+        forecast,
+        staffing,
         tasks,
     }))
 }
 
+fn load_vacancy(
+    repository: &SharedVacancyRepository,
+    vacancy_id: &str,
+) -> Result<VacancyRecord, AppError> {
+    let id = VacancyId::parse(vacancy_id).ok_or(RepositoryError::NotFound)?;
+    repository
+        .fetch(&id)?
+        .ok_or_else(|| RepositoryError::NotFound.into())
+}
+
+pub(crate) async fn create_vacancy_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Json(payload): Json<CreateVacancyRequest>,
+) -> Result<(StatusCode, Json<VacancyResponse>), AppError> {
+    VacancyWorkflowInstance::check_schedule(payload.vacancy_start, payload.target_move_in)?;
+    let registry = BlueprintRegistry::shared_builtin();
+    let blueprint = registry.select(payload.blueprint.as_deref())?;
+    let mut instance = VacancyWorkflowInstance::with_calendar(
+        blueprint,
+        payload.vacancy_start,
+        payload.target_move_in,
+        BusinessCalendar::with_holidays(payload.holidays),
+    );
+    instance.set_monthly_rent(payload.monthly_rent);
+    let record = repository.create(VacancyRecord {
+        id: VacancyId::new(payload.property_code, payload.unit_id)?,
+        instance,
+        version: 0,
+    })?;
+
+    Ok((StatusCode::CREATED, Json(VacancyResponse::from(&record))))
+}
+
+pub(crate) async fn fetch_vacancy_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Path(vacancy_id): Path<String>,
+) -> Result<Json<VacancyResponse>, AppError> {
+    let record = load_vacancy(&repository, &vacancy_id)?;
+    Ok(Json(VacancyResponse::from(&record)))
+}
+
+pub(crate) async fn update_task_status_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Path((vacancy_id, task_key)): Path<(String, String)>,
+    Json(payload): Json<UpdateTaskStatusRequest>,
+) -> Result<Json<TaskDetailView>, AppError> {
+    let mut record = load_vacancy(&repository, &vacancy_id)?;
+    let mut change = StatusChange::new(payload.status, payload.completed_on);
+    change.actor = payload.actor;
+    change.note = payload.note;
+    record.instance.apply_status_change(&task_key, change)?;
+
+    let view = record
+        .instance
+        .task_details()
+        .into_iter()
+        .find(|task| task.key == task_key)
+        .ok_or_else(|| VacancyError::TaskNotFound(task_key.clone()))?;
+    repository.update(record)?;
+
+    Ok(Json(view))
+}
+
+pub(crate) async fn attach_evidence_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Path((vacancy_id, task_key)): Path<(String, String)>,
+    Json(payload): Json<AttachEvidenceRequest>,
+) -> Result<(StatusCode, Json<TaskDetailView>), AppError> {
+    let mut record = load_vacancy(&repository, &vacancy_id)?;
+    record.instance.attach_evidence(
+        &task_key,
+        ComplianceEvidence {
+            topic: payload.topic,
+            document: payload.document,
+            url: payload.url,
+            captured_at: payload.captured_at.unwrap_or_else(Utc::now),
+        },
+    )?;
+
+    let view = record
+        .instance
+        .task_details()
+        .into_iter()
+        .find(|task| task.key == task_key)
+        .ok_or_else(|| VacancyError::TaskNotFound(task_key.clone()))?;
+    repository.update(record)?;
+
+    Ok((StatusCode::CREATED, Json(view)))
+}
+
+pub(crate) async fn update_subtask_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Path((vacancy_id, task_key, index)): Path<(String, String, usize)>,
+    Json(payload): Json<UpdateSubtaskRequest>,
+) -> Result<Json<TaskDetailView>, AppError> {
+    let mut record = load_vacancy(&repository, &vacancy_id)?;
+    record
+        .instance
+        .set_subtask(&task_key, index, payload.completed, payload.completed_on)?;
+
+    let view = record
+        .instance
+        .task_details()
+        .into_iter()
+        .find(|task| task.key == task_key)
+        .ok_or_else(|| VacancyError::TaskNotFound(task_key.clone()))?;
+    repository.update(record)?;
+
+    Ok(Json(view))
+}
+
+pub(crate) async fn reschedule_vacancy_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Path(vacancy_id): Path<String>,
+    Json(payload): Json<RescheduleVacancyRequest>,
+) -> Result<Json<VacancyResponse>, AppError> {
+    let mut record = load_vacancy(&repository, &vacancy_id)?;
+    record
+        .instance
+        .reschedule(payload.vacancy_start, payload.target_move_in)?;
+    repository.update(record.clone())?;
+
+    Ok(Json(VacancyResponse::from(&record)))
+}
+
+pub(crate) async fn dispatch_automation_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Extension(dispatcher): Extension<SharedAutomationDispatcher>,
+    Path(vacancy_id): Path<String>,
+    Json(payload): Json<DispatchAutomationRequest>,
+) -> Result<Json<DispatchOutcome>, AppError> {
+    let record = load_vacancy(&repository, &vacancy_id)?;
+    let today = payload.today.unwrap_or_else(|| Local::now().date_naive());
+    let triggers = record.instance.automation_triggers(today);
+    let outcome = dispatcher.dispatch(&record.id.to_string(), &triggers, today)?;
+
+    Ok(Json(outcome))
+}
+
+pub(crate) async fn record_snapshot_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Path(vacancy_id): Path<String>,
+    Json(payload): Json<RecordSnapshotRequest>,
+) -> Result<(StatusCode, Json<ReadinessSnapshot>), AppError> {
+    let mut record = load_vacancy(&repository, &vacancy_id)?;
+    let today = payload.today.unwrap_or_else(|| Local::now().date_naive());
+    let snapshot = record.instance.record_snapshot(today).clone();
+    repository.update(record)?;
+
+    Ok((StatusCode::CREATED, Json(snapshot)))
+}
+
+pub(crate) async fn readiness_trend_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Path(vacancy_id): Path<String>,
+) -> Result<Json<ReadinessTrend>, AppError> {
+    let record = load_vacancy(&repository, &vacancy_id)?;
+    Ok(Json(record.instance.readiness_trend()))
+}
+
+pub(crate) async fn portfolio_report_endpoint(
+    Extension(repository): Extension<SharedVacancyRepository>,
+    Json(payload): Json<PortfolioReportRequest>,
+) -> Result<Json<PortfolioReport>, AppError> {
+    let records = match payload.property_code.as_deref() {
+        Some(property_code) => repository.list_by_property(property_code)?,
+        None => repository.list_all()?,
+    };
+    let today = payload.today.unwrap_or_else(|| Local::now().date_naive());
+    let overdue_limit = payload
+        .overdue_limit
+        .unwrap_or(DEFAULT_PORTFOLIO_OVERDUE_LIMIT);
+
+    Ok(Json(portfolio_report(&records, today, overdue_limit)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,9 +630,21 @@ mod tests {
             today: None,
             include_tasks: false,
             apollo_csv: None,
+            holidays: Vec::new(),
+            blueprint: None,
+            staff: Vec::new(),
+            assignment_policy: None,
+            warning_window_days: None,
+            readiness_model: ReadinessModel::default(),
+            monthly_rent: None,
+            listing: None,
+            history_csv: Vec::new(),
+            match_threshold: None,
+            apollo_name_map: BTreeMap::new(),
+            duplicate_policy: DuplicateRowPolicy::default(),
         };
 
-        let Json(body) = vacancy_report_endpoint(Json(request))
+        let Json(body) = vacancy_report_endpoint(Extension(name_map()), Json(request))
             .await
             .expect("report builds");
 
@@ -181,6 +654,47 @@ mod tests {
         assert!(body.insights.readiness_score <= 100);
     }
 
+    #[tokio::test]
+    async fn vacancy_report_endpoint_applies_readiness_model() {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let csv = "Task ID,Created At,Completed At,Last Modified,Name\n1,2025-09-24T10:00:00Z,2025-09-25T12:15:00Z,2025-09-25T12:15:00Z,Create and Publish Listing - Leasing Agent\n";
+        let request = |readiness_model| VacancyReportRequest {
+            vacancy_start,
+            target_move_in,
+            today: Some(vacancy_start),
+            include_tasks: false,
+            apollo_csv: Some(csv.to_string()),
+            holidays: Vec::new(),
+            blueprint: None,
+            staff: Vec::new(),
+            assignment_policy: None,
+            warning_window_days: None,
+            readiness_model,
+            monthly_rent: None,
+            listing: None,
+            history_csv: Vec::new(),
+            match_threshold: None,
+            apollo_name_map: BTreeMap::new(),
+            duplicate_policy: DuplicateRowPolicy::default(),
+        };
+
+        let Json(default) = vacancy_report_endpoint(
+            Extension(name_map()),
+            Json(request(ReadinessModel::TaskCompletion)),
+        )
+        .await
+        .expect("report builds");
+        let Json(weighted) = vacancy_report_endpoint(
+            Extension(name_map()),
+            Json(request(ReadinessModel::Criticality)),
+        )
+        .await
+        .expect("report builds");
+
+        assert_eq!(default.insights.readiness_score, 10);
+        assert!(weighted.insights.readiness_score < default.insights.readiness_score);
+    }
+
     #[tokio::test]
     async fn vacancy_report_endpoint_can_include_tasks() {
         let (vacancy_start, target_move_in) = sample_dates();
@@ -192,9 +706,21 @@ mod tests {
             apollo_csv: Some(
                 "Task ID,Created At,Completed At,Last Modified,Name\n1,2025-09-24T10:00:00Z,2025-09-25T12:15:00Z,2025-09-25T12:15:00Z,Create and Publish Listing - Leasing Agent\n".to_string(),
             ),
+            holidays: Vec::new(),
+            blueprint: None,
+            staff: Vec::new(),
+            assignment_policy: None,
+            warning_window_days: None,
+            readiness_model: ReadinessModel::default(),
+            monthly_rent: None,
+            listing: None,
+            history_csv: Vec::new(),
+            match_threshold: None,
+            apollo_name_map: BTreeMap::new(),
+            duplicate_policy: DuplicateRowPolicy::default(),
         };
 
-        let Json(body) = vacancy_report_endpoint(Json(request))
+        let Json(body) = vacancy_report_endpoint(Extension(name_map()), Json(request))
             .await
             .expect("report builds");
 
@@ -203,5 +729,864 @@ mod tests {
         assert!(!tasks.is_empty());
         assert_eq!(tasks[0].status_label, "Completed");
         assert!(body.insights.focus_stage.is_some());
+        let diagnostics = body
+            .import_diagnostics
+            .expect("apollo import reports diagnostics");
+        assert_eq!(diagnostics.rows_read, 1);
+        assert!(diagnostics.unmapped_rows.is_empty());
+        assert_eq!(diagnostics.missing_tasks.len(), tasks.len() - 1);
+    }
+
+    #[tokio::test]
+    async fn vacancy_report_endpoint_applies_request_name_map() {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let request = |apollo_name_map| {
+            VacancyReportRequest {
+            vacancy_start,
+            target_move_in,
+            today: Some(vacancy_start),
+            include_tasks: true,
+            apollo_csv: Some(
+                "Name,Created At,Completed At,Last Modified\nList unit on Zillow,2025-09-24,2025-09-25,2025-09-25\n"
+                    .to_string(),
+            ),
+            holidays: Vec::new(),
+            blueprint: None,
+            staff: Vec::new(),
+            assignment_policy: None,
+            warning_window_days: None,
+            readiness_model: ReadinessModel::default(),
+            monthly_rent: None,
+            listing: None,
+            history_csv: Vec::new(),
+            match_threshold: None,
+            apollo_name_map,
+            duplicate_policy: DuplicateRowPolicy::default(),
+        }
+        };
+        let configured: SharedApolloNameMap = Arc::new(
+            [("List unit on Zillow", "marketing_update_appfolio")]
+                .into_iter()
+                .collect(),
+        );
+
+        let Json(body) = vacancy_report_endpoint(
+            Extension(configured.clone()),
+            Json(request(BTreeMap::from([(
+                "list unit on zillow".to_string(),
+                "marketing_publish_listing".to_string(),
+            )]))),
+        )
+        .await
+        .expect("report builds");
+        let tasks = body.tasks.expect("tasks returned");
+        let status_of = |key: &str| {
+            tasks
+                .iter()
+                .find(|task| task.key == key)
+                .map(|task| task.status)
+                .expect("task present")
+        };
+        assert_eq!(
+            status_of("marketing_publish_listing"),
+            TaskStatus::Completed
+        );
+        assert_eq!(
+            status_of("marketing_update_appfolio"),
+            TaskStatus::NotStarted
+        );
+        assert!(body
+            .import_diagnostics
+            .expect("diagnostics returned")
+            .unmapped_rows
+            .is_empty());
+
+        let error = vacancy_report_endpoint(
+            Extension(configured),
+            Json(request(BTreeMap::from([(
+                "List unit on Zillow".to_string(),
+                "leasing_hap_contract".to_string(),
+            )]))),
+        )
+        .await
+        .expect_err("section 8 task is not in the lihtc blueprint");
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn configured_name_map_does_not_fail_other_programs() {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let configured: SharedApolloNameMap = Arc::new(
+            [("Finalize TIC", "leasing_lihtc_certification")]
+                .into_iter()
+                .collect(),
+        );
+        let request: VacancyReportRequest = serde_json::from_value(json!({
+            "vacancy_start": vacancy_start,
+            "target_move_in": target_move_in,
+            "today": vacancy_start,
+            "blueprint": "market_rate",
+            "apollo_csv": "Name,Created At,Completed At,Last Modified\nFinalize TIC,2025-09-24,2025-09-25,2025-09-25\n",
+        }))
+        .expect("valid request");
+
+        let Json(body) = vacancy_report_endpoint(Extension(configured), Json(request))
+            .await
+            .expect("market rate import ignores the lihtc-only entry");
+        let diagnostics = body.import_diagnostics.expect("diagnostics returned");
+        assert_eq!(diagnostics.excluded_rows.len(), 1);
+        assert_eq!(
+            diagnostics.excluded_rows[0].task_key,
+            "leasing_lihtc_certification"
+        );
+    }
+
+    fn name_map() -> SharedApolloNameMap {
+        Arc::new(ApolloNameMap::default())
+    }
+
+    fn vacancy_repository() -> SharedVacancyRepository {
+        Arc::new(tenant_ai::workflows::vacancy::InMemoryVacancyRepository::new())
+    }
+
+    async fn open_vacancy(repository: &SharedVacancyRepository) -> VacancyResponse {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let request = CreateVacancyRequest {
+            property_code: "OAK".to_string(),
+            unit_id: "101".to_string(),
+            vacancy_start,
+            target_move_in,
+            blueprint: None,
+            holidays: Vec::new(),
+            monthly_rent: None,
+        };
+
+        let (status, Json(body)) =
+            create_vacancy_endpoint(Extension(repository.clone()), Json(request))
+                .await
+                .expect("vacancy opens");
+        assert_eq!(status, StatusCode::CREATED);
+        body
+    }
+
+    #[tokio::test]
+    async fn vacancy_endpoints_track_task_status() {
+        let repository = vacancy_repository();
+        let created = open_vacancy(&repository).await;
+        assert_eq!(created.id, "OAK:101");
+        assert!(!created.tasks.is_empty());
+
+        let request = UpdateTaskStatusRequest {
+            status: TaskStatus::Completed,
+            completed_on: NaiveDate::from_ymd_opt(2025, 9, 25),
+            actor: Some(VacancyRole::LeasingAgent),
+            note: Some("Listing live".to_string()),
+        };
+        let Json(task) = update_task_status_endpoint(
+            Extension(repository.clone()),
+            Path((created.id.clone(), "marketing_publish_listing".to_string())),
+            Json(request),
+        )
+        .await
+        .expect("status updates");
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert_eq!(task.history.len(), 1);
+
+        let Json(fetched) =
+            fetch_vacancy_endpoint(Extension(repository.clone()), Path(created.id.clone()))
+                .await
+                .expect("vacancy fetched");
+        let publish = fetched
+            .tasks
+            .iter()
+            .find(|task| task.key == "marketing_publish_listing")
+            .expect("publish listing task present");
+        assert_eq!(publish.status, TaskStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn subtask_endpoint_derives_task_completion() {
+        let repository = vacancy_repository();
+        let created = open_vacancy(&repository).await;
+        let task_key = "marketing_update_appfolio".to_string();
+        let completed_on = NaiveDate::from_ymd_opt(2025, 9, 25);
+
+        let Json(started) = update_subtask_endpoint(
+            Extension(repository.clone()),
+            Path((created.id.clone(), task_key.clone(), 0)),
+            Json(UpdateSubtaskRequest {
+                completed: true,
+                completed_on,
+            }),
+        )
+        .await
+        .expect("subtask checks off");
+        assert_eq!(started.status, TaskStatus::InProgress);
+        assert!(started.subtasks[0].completed);
+        assert_eq!(
+            started.deliverables,
+            started
+                .subtasks
+                .iter()
+                .map(|subtask| subtask.name.clone())
+                .collect::<Vec<_>>()
+        );
+
+        let Json(finished) = update_subtask_endpoint(
+            Extension(repository.clone()),
+            Path((created.id.clone(), task_key.clone(), 1)),
+            Json(UpdateSubtaskRequest {
+                completed: true,
+                completed_on,
+            }),
+        )
+        .await
+        .expect("last subtask checks off");
+        assert_eq!(finished.status, TaskStatus::Completed);
+        assert_eq!(finished.completed_on, completed_on);
+
+        let missing = update_subtask_endpoint(
+            Extension(repository),
+            Path((created.id, task_key, 9)),
+            Json(UpdateSubtaskRequest {
+                completed: true,
+                completed_on: None,
+            }),
+        )
+        .await
+        .expect_err("unknown subtask");
+        assert_eq!(missing.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn evidence_endpoint_clears_warning_for_documented_note() {
+        let repository = vacancy_repository();
+        let created = open_vacancy(&repository).await;
+        let topic = "Iowa Code § 562A.29 reasonable re-rental efforts".to_string();
+        let document = DocumentDescriptor {
+            name: "Syndication log".to_string(),
+            category: tenant_ai::workflows::vacancy::applications::DocumentCategory::Misc,
+            storage_key: "vacancies/OAK-101/syndication.pdf".to_string(),
+        };
+
+        let (status, Json(task)) = attach_evidence_endpoint(
+            Extension(repository.clone()),
+            Path((created.id.clone(), "marketing_publish_listing".to_string())),
+            Json(AttachEvidenceRequest {
+                topic: topic.clone(),
+                document: document.clone(),
+                url: Some("https://example.com/listing".to_string()),
+                captured_at: None,
+            }),
+        )
+        .await
+        .expect("evidence attaches");
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(task.evidence.len(), 1);
+        assert_eq!(task.evidence[0].topic, topic);
+
+        let unknown = attach_evidence_endpoint(
+            Extension(repository),
+            Path((created.id, "marketing_publish_listing".to_string())),
+            Json(AttachEvidenceRequest {
+                topic: "Not a note".to_string(),
+                document,
+                url: None,
+                captured_at: None,
+            }),
+        )
+        .await
+        .expect_err("unknown topic rejected");
+        assert_eq!(unknown.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn create_vacancy_rejects_ids_containing_separator() {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let error = create_vacancy_endpoint(
+            Extension(vacancy_repository()),
+            Json(CreateVacancyRequest {
+                property_code: "OAK".to_string(),
+                unit_id: "B:2".to_string(),
+                vacancy_start,
+                target_move_in,
+                blueprint: None,
+                holidays: Vec::new(),
+                monthly_rent: None,
+            }),
+        )
+        .await
+        .expect_err("ambiguous vacancy id rejected");
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn create_vacancy_rejects_move_in_before_vacancy_start() {
+        let repository = vacancy_repository();
+        let (vacancy_start, target_move_in) = sample_dates();
+        let error = create_vacancy_endpoint(
+            Extension(repository.clone()),
+            Json(CreateVacancyRequest {
+                property_code: "OAK".to_string(),
+                unit_id: "101".to_string(),
+                vacancy_start: target_move_in,
+                target_move_in: vacancy_start,
+                blueprint: None,
+                holidays: Vec::new(),
+                monthly_rent: None,
+            }),
+        )
+        .await
+        .expect_err("inverted vacancy window rejected");
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let missing = fetch_vacancy_endpoint(Extension(repository), Path("OAK:101".into()))
+            .await
+            .expect_err("rejected vacancy is not stored");
+        assert_eq!(missing.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn vacancy_endpoints_map_errors_to_http_status() {
+        let repository = vacancy_repository();
+        let created = open_vacancy(&repository).await;
+
+        let unknown_task = update_task_status_endpoint(
+            Extension(repository.clone()),
+            Path((created.id.clone(), "missing_task".to_string())),
+            Json(UpdateTaskStatusRequest {
+                status: TaskStatus::InProgress,
+                completed_on: None,
+                actor: None,
+                note: None,
+            }),
+        )
+        .await
+        .expect_err("unknown task rejected");
+        assert_eq!(unknown_task.into_response().status(), StatusCode::NOT_FOUND);
+
+        let waiting = update_task_status_endpoint(
+            Extension(repository.clone()),
+            Path((
+                created.id.clone(),
+                "handoff_start_new_resident_workflow".to_string(),
+            )),
+            Json(UpdateTaskStatusRequest {
+                status: TaskStatus::Completed,
+                completed_on: None,
+                actor: None,
+                note: None,
+            }),
+        )
+        .await
+        .expect_err("prerequisites enforced");
+        assert_eq!(waiting.into_response().status(), StatusCode::CONFLICT);
+
+        let missing = fetch_vacancy_endpoint(Extension(repository.clone()), Path("ELM:9".into()))
+            .await
+            .expect_err("unknown vacancy");
+        assert_eq!(missing.into_response().status(), StatusCode::NOT_FOUND);
+
+        let duplicate = create_vacancy_endpoint(
+            Extension(repository),
+            Json(CreateVacancyRequest {
+                property_code: "OAK".to_string(),
+                unit_id: "101".to_string(),
+                vacancy_start: sample_dates().0,
+                target_move_in: sample_dates().1,
+                blueprint: Some("standard".to_string()),
+                holidays: Vec::new(),
+                monthly_rent: None,
+            }),
+        )
+        .await
+        .expect_err("duplicate vacancy rejected");
+        assert_eq!(duplicate.into_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn portfolio_report_endpoint_rolls_up_tracked_vacancies() {
+        let repository = vacancy_repository();
+        open_vacancy(&repository).await;
+        let (vacancy_start, target_move_in) = sample_dates();
+        let (status, _) = create_vacancy_endpoint(
+            Extension(repository.clone()),
+            Json(CreateVacancyRequest {
+                property_code: "ELM".to_string(),
+                unit_id: "7".to_string(),
+                vacancy_start,
+                target_move_in,
+                blueprint: None,
+                holidays: Vec::new(),
+                monthly_rent: Some(1825),
+            }),
+        )
+        .await
+        .expect("second vacancy opens");
+        assert_eq!(status, StatusCode::CREATED);
+
+        let Json(portfolio) = portfolio_report_endpoint(
+            Extension(repository.clone()),
+            Json(PortfolioReportRequest {
+                today: Some(target_move_in),
+                overdue_limit: Some(3),
+                ..PortfolioReportRequest::default()
+            }),
+        )
+        .await
+        .expect("portfolio builds");
+        assert_eq!(portfolio.open_vacancies, 2);
+        assert_eq!(portfolio.properties.len(), 2);
+        assert_eq!(portfolio.top_overdue_tasks.len(), 3);
+        assert_eq!(portfolio.average_days_on_market, 14.0);
+        assert_eq!(portfolio.vacancy_loss.units_with_rent, 1);
+        assert_eq!(portfolio.vacancy_loss.daily_loss, 60.0);
+        assert_eq!(portfolio.vacancy_loss.incurred_loss, 840.0);
+
+        let Json(oak_only) = portfolio_report_endpoint(
+            Extension(repository),
+            Json(PortfolioReportRequest {
+                property_code: Some("OAK".to_string()),
+                ..PortfolioReportRequest::default()
+            }),
+        )
+        .await
+        .expect("portfolio builds");
+        assert_eq!(oak_only.open_vacancies, 1);
+        assert_eq!(oak_only.units[0].vacancy_id, "OAK:101");
+    }
+
+    #[tokio::test]
+    async fn vacancy_report_endpoint_prices_vacancy_loss() {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let request = |monthly_rent| VacancyReportRequest {
+            vacancy_start,
+            target_move_in,
+            today: Some(vacancy_start + chrono::Duration::days(4)),
+            include_tasks: false,
+            apollo_csv: None,
+            holidays: Vec::new(),
+            blueprint: None,
+            staff: Vec::new(),
+            assignment_policy: None,
+            warning_window_days: None,
+            readiness_model: ReadinessModel::default(),
+            monthly_rent,
+            listing: Some(VacancyListingSnapshot {
+                unit_id: "101".to_string(),
+                property_code: "OAK".to_string(),
+                listed_rent: 1825,
+                available_on: target_move_in,
+                deposit_required: 1825,
+            }),
+            history_csv: Vec::new(),
+            match_threshold: None,
+            apollo_name_map: BTreeMap::new(),
+            duplicate_policy: DuplicateRowPolicy::default(),
+        };
+
+        let Json(listed) = vacancy_report_endpoint(Extension(name_map()), Json(request(None)))
+            .await
+            .expect("report builds");
+        let loss = listed
+            .insights
+            .vacancy_loss
+            .expect("listing prices the vacancy");
+        assert_eq!(loss.monthly_rent, 1825);
+        assert_eq!(loss.daily_loss, 60.0);
+        assert_eq!(loss.days_on_market, 4);
+        assert_eq!(loss.incurred_loss, 240.0);
+        assert_eq!(loss.remaining_loss, 600.0);
+        assert_eq!(loss.projected_loss, 840.0);
+
+        let Json(overridden) =
+            vacancy_report_endpoint(Extension(name_map()), Json(request(Some(3650))))
+                .await
+                .expect("report builds");
+        let loss = overridden.insights.vacancy_loss.expect("rent supplied");
+        assert_eq!(loss.daily_loss, 120.0);
+    }
+
+    #[tokio::test]
+    async fn vacancy_report_endpoint_forecasts_move_in_from_history() {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let request = |history_csv| VacancyReportRequest {
+            vacancy_start,
+            target_move_in,
+            today: Some(vacancy_start),
+            include_tasks: false,
+            apollo_csv: None,
+            holidays: Vec::new(),
+            blueprint: None,
+            staff: Vec::new(),
+            assignment_policy: None,
+            warning_window_days: None,
+            readiness_model: ReadinessModel::default(),
+            monthly_rent: None,
+            listing: None,
+            history_csv,
+            match_threshold: None,
+            apollo_name_map: BTreeMap::new(),
+            duplicate_policy: DuplicateRowPolicy::default(),
+        };
+        let slow_listing = "Name,Created At,Completed At,Last Modified\n\
+Create and Publish Listing - Leasing Agent,2025-06-01,2025-06-21,2025-06-21\n\
+Create and Publish Listing - Leasing Agent,2025-07-01,2025-07-26,2025-07-26\n\
+Create and Publish Listing - Leasing Agent,2025-08-01,2025-08-31,2025-08-31\n";
+
+        let Json(baseline) =
+            vacancy_report_endpoint(Extension(name_map()), Json(request(Vec::new())))
+                .await
+                .expect("report builds");
+        assert!(baseline.forecast.move_in_ready_p50 <= baseline.forecast.move_in_ready_p90);
+        assert!(!baseline.forecast.stages.is_empty());
+
+        let Json(slow) = vacancy_report_endpoint(
+            Extension(name_map()),
+            Json(request(vec![slow_listing.to_string()])),
+        )
+        .await
+        .expect("report builds");
+        assert!(slow.forecast.move_in_ready_p50 > target_move_in);
+        assert_eq!(slow.forecast.on_time_probability, 0.0);
+        assert_eq!(
+            slow.forecast.history_backed_tasks,
+            vec!["marketing_publish_listing".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn history_csv_uses_the_request_and_configured_name_maps() {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let configured: SharedApolloNameMap = Arc::new(
+            [("Zillow listing", "marketing_publish_listing")]
+                .into_iter()
+                .collect(),
+        );
+        let history = "Name,Created At,Completed At,Last Modified\n\
+Zillow listing,2025-06-02,2025-06-04,2025-06-04\n\
+Zillow listing,2025-07-07,2025-07-09,2025-07-09\n\
+Showings,2025-08-04,2025-08-06,2025-08-06\n\
+Showings,2025-08-11,2025-08-13,2025-08-13\n\
+Showings,2025-08-18,2025-08-20,2025-08-20\n";
+        let request: VacancyReportRequest = serde_json::from_value(json!({
+            "vacancy_start": vacancy_start,
+            "target_move_in": target_move_in,
+            "today": vacancy_start,
+            "history_csv": [history, history],
+            "apollo_name_map": { "Showings": "screening_manage_inquiries" },
+        }))
+        .expect("valid request");
+
+        let Json(body) = vacancy_report_endpoint(Extension(configured), Json(request))
+            .await
+            .expect("report builds");
+        assert_eq!(
+            body.forecast.history_backed_tasks,
+            vec![
+                "marketing_publish_listing".to_string(),
+                "screening_manage_inquiries".to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reschedule_endpoint_keeps_status_and_logs_shift() {
+        let repository = vacancy_repository();
+        let created = open_vacancy(&repository).await;
+        let Json(published) = update_task_status_endpoint(
+            Extension(repository.clone()),
+            Path((created.id.clone(), "marketing_publish_listing".to_string())),
+            Json(UpdateTaskStatusRequest {
+                status: TaskStatus::Completed,
+                completed_on: None,
+                actor: None,
+                note: None,
+            }),
+        )
+        .await
+        .expect("status updates");
+        assert_eq!(published.status, TaskStatus::Completed);
+
+        let Json(body) = reschedule_vacancy_endpoint(
+            Extension(repository.clone()),
+            Path(created.id.clone()),
+            Json(RescheduleVacancyRequest {
+                vacancy_start: created.vacancy_start,
+                target_move_in: created.target_move_in + chrono::Duration::days(7),
+            }),
+        )
+        .await
+        .expect("vacancy reschedules");
+
+        assert_eq!(
+            body.target_move_in,
+            created.target_move_in + chrono::Duration::days(7)
+        );
+        assert_eq!(body.schedule_changes.len(), 1);
+        assert!(!body.schedule_changes[0].shifted_tasks.is_empty());
+        let publish = body
+            .tasks
+            .iter()
+            .find(|task| task.key == "marketing_publish_listing")
+            .expect("publish listing task present");
+        assert_eq!(publish.status, TaskStatus::Completed);
+
+        let inverted = reschedule_vacancy_endpoint(
+            Extension(repository.clone()),
+            Path(created.id.clone()),
+            Json(RescheduleVacancyRequest {
+                vacancy_start: created.target_move_in,
+                target_move_in: created.vacancy_start,
+            }),
+        )
+        .await
+        .expect_err("move-in before vacancy start");
+        assert_eq!(inverted.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let Json(unchanged) = reschedule_vacancy_endpoint(
+            Extension(repository),
+            Path(created.id),
+            Json(RescheduleVacancyRequest {
+                vacancy_start: body.vacancy_start,
+                target_move_in: body.target_move_in,
+            }),
+        )
+        .await
+        .expect("same window is accepted");
+        assert_eq!(unchanged.schedule_changes.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn automation_endpoint_dedupes_same_day_dispatch() {
+        let repository = vacancy_repository();
+        let created = open_vacancy(&repository).await;
+        let alerts = Arc::new(crate::infra::InMemoryAlertPublisher::default());
+        let dispatcher: SharedAutomationDispatcher = Arc::new(
+            tenant_ai::workflows::vacancy::AutomationDispatcher::new(alerts.clone()),
+        );
+        let today = Some(created.vacancy_start + chrono::Duration::days(3));
+
+        let Json(first) = dispatch_automation_endpoint(
+            Extension(repository.clone()),
+            Extension(dispatcher.clone()),
+            Path(created.id.clone()),
+            Json(DispatchAutomationRequest { today }),
+        )
+        .await
+        .expect("automation dispatches");
+        assert!(!first.sent.is_empty());
+        assert_eq!(alerts.vacancy_events().len(), first.sent.len());
+        assert!(alerts
+            .vacancy_events()
+            .iter()
+            .all(|alert| alert.vacancy_id == "OAK:101"));
+        assert!(alerts.events().is_empty());
+
+        let Json(repeat) = dispatch_automation_endpoint(
+            Extension(repository),
+            Extension(dispatcher),
+            Path(created.id),
+            Json(DispatchAutomationRequest { today }),
+        )
+        .await
+        .expect("automation dispatches");
+        assert!(repeat.sent.is_empty());
+        assert_eq!(repeat.suppressed.len(), first.sent.len());
+        assert_eq!(alerts.vacancy_events().len(), first.sent.len());
+    }
+
+    #[tokio::test]
+    async fn snapshot_endpoints_build_readiness_trend() {
+        let repository = vacancy_repository();
+        let created = open_vacancy(&repository).await;
+        let day_one = created.vacancy_start + chrono::Duration::days(1);
+        let day_two = day_one + chrono::Duration::days(1);
+
+        let (status, Json(first)) = record_snapshot_endpoint(
+            Extension(repository.clone()),
+            Path(created.id.clone()),
+            Json(RecordSnapshotRequest {
+                today: Some(day_one),
+            }),
+        )
+        .await
+        .expect("snapshot recorded");
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(first.readiness_score, 0);
+
+        let Json(published) = update_task_status_endpoint(
+            Extension(repository.clone()),
+            Path((created.id.clone(), "marketing_publish_listing".to_string())),
+            Json(UpdateTaskStatusRequest {
+                status: TaskStatus::Completed,
+                completed_on: Some(day_two),
+                actor: None,
+                note: None,
+            }),
+        )
+        .await
+        .expect("status updates");
+        assert_eq!(published.status, TaskStatus::Completed);
+        for _ in 0..2 {
+            let (_, Json(snapshot)) = record_snapshot_endpoint(
+                Extension(repository.clone()),
+                Path(created.id.clone()),
+                Json(RecordSnapshotRequest {
+                    today: Some(day_two),
+                }),
+            )
+            .await
+            .expect("snapshot recorded");
+            assert_eq!(snapshot.date, day_two);
+        }
+
+        let Json(trend) =
+            readiness_trend_endpoint(Extension(repository.clone()), Path(created.id.clone()))
+                .await
+                .expect("trend loads");
+        assert_eq!(trend.points.len(), 2);
+        assert_eq!(trend.points[0].date, day_one);
+        assert_eq!(trend.points[1].date, day_two);
+        assert_eq!(trend.points[1].completed_tasks, 1);
+        assert!(trend.score_change > 0);
+
+        let missing = readiness_trend_endpoint(Extension(repository), Path("OAK:999".to_string()))
+            .await
+            .expect_err("unknown vacancy");
+        assert_eq!(missing.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn vacancy_report_endpoint_selects_program_blueprint() {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let request = VacancyReportRequest {
+            vacancy_start,
+            target_move_in,
+            today: Some(vacancy_start),
+            include_tasks: true,
+            apollo_csv: None,
+            holidays: Vec::new(),
+            blueprint: Some("market_rate".to_string()),
+            staff: Vec::new(),
+            assignment_policy: None,
+            warning_window_days: None,
+            readiness_model: ReadinessModel::default(),
+            monthly_rent: None,
+            listing: None,
+            history_csv: Vec::new(),
+            match_threshold: None,
+            apollo_name_map: BTreeMap::new(),
+            duplicate_policy: DuplicateRowPolicy::default(),
+        };
+
+        let Json(body) = vacancy_report_endpoint(Extension(name_map()), Json(request))
+            .await
+            .expect("report builds");
+
+        assert_eq!(body.blueprint, "market_rate");
+        let tasks = body.tasks.expect("tasks returned");
+        assert!(tasks
+            .iter()
+            .all(|task| task.key != "leasing_lihtc_certification"));
+        assert!(body
+            .compliance_alerts
+            .iter()
+            .all(|alert| !alert.topic.contains("LIHTC")));
+
+        let unknown = vacancy_report_endpoint(
+            Extension(name_map()),
+            Json(VacancyReportRequest {
+                vacancy_start,
+                target_move_in,
+                today: None,
+                include_tasks: false,
+                apollo_csv: None,
+                holidays: Vec::new(),
+                blueprint: Some("co_op".to_string()),
+                staff: Vec::new(),
+                assignment_policy: None,
+                warning_window_days: None,
+                readiness_model: ReadinessModel::default(),
+                monthly_rent: None,
+                listing: None,
+                history_csv: Vec::new(),
+                match_threshold: None,
+                apollo_name_map: BTreeMap::new(),
+                duplicate_policy: DuplicateRowPolicy::default(),
+            }),
+        )
+        .await
+        .expect_err("unknown blueprint rejected");
+        assert_eq!(unknown.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn vacancy_report_endpoint_assigns_staff_roster() {
+        let (vacancy_start, target_move_in) = sample_dates();
+        let agent = |id: &str| StaffMember {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            roles: vec![VacancyRole::LeasingAgent],
+        };
+        let request = VacancyReportRequest {
+            vacancy_start,
+            target_move_in,
+            today: Some(vacancy_start),
+            include_tasks: true,
+            apollo_csv: None,
+            holidays: Vec::new(),
+            blueprint: None,
+            staff: vec![agent("ana"), agent("ben")],
+            assignment_policy: Some(AssignmentPolicy::RoundRobin),
+            warning_window_days: None,
+            readiness_model: ReadinessModel::default(),
+            monthly_rent: None,
+            listing: None,
+            history_csv: Vec::new(),
+            match_threshold: None,
+            apollo_name_map: BTreeMap::new(),
+            duplicate_policy: DuplicateRowPolicy::default(),
+        };
+
+        let Json(body) = vacancy_report_endpoint(Extension(name_map()), Json(request))
+            .await
+            .expect("report builds");
+
+        let staffing = body.staffing.expect("staffing returned");
+        let open: Vec<usize> = staffing.staff_load.iter().map(|entry| entry.open).collect();
+        assert_eq!(open.len(), 2);
+        assert!(open[0].abs_diff(open[1]) <= 1);
+        let tasks = body.tasks.expect("tasks returned");
+        assert!(tasks
+            .iter()
+            .filter(|task| task.role == VacancyRole::LeasingAgent)
+            .all(|task| task.assignee.is_some()));
+
+        let duplicate = vacancy_report_endpoint(
+            Extension(name_map()),
+            Json(VacancyReportRequest {
+                vacancy_start,
+                target_move_in,
+                today: None,
+                include_tasks: false,
+                apollo_csv: None,
+                holidays: Vec::new(),
+                blueprint: None,
+                staff: vec![agent("ana"), agent("ana")],
+                assignment_policy: None,
+                warning_window_days: None,
+                readiness_model: ReadinessModel::default(),
+                monthly_rent: None,
+                listing: None,
+                history_csv: Vec::new(),
+                match_threshold: None,
+                apollo_name_map: BTreeMap::new(),
+                duplicate_policy: DuplicateRowPolicy::default(),
+            }),
+        )
+        .await
+        .expect_err("duplicate staff rejected");
+        assert_eq!(duplicate.into_response().status(), StatusCode::BAD_REQUEST);
     }
 }
@@ -1,6 +1,7 @@
 use crate::cli::ServeArgs;
 use crate::infra::{
     default_evaluation_config, AppState, InMemoryAlertPublisher, InMemoryApplicationRepository,
+    SharedApolloNameMap, SharedAutomationDispatcher, SharedVacancyRepository,
 };
 use crate::routes::with_application_routes;
 use axum::Extension;
@@ -10,7 +11,11 @@ use std::sync::Arc;
 use tenant_ai::config::AppConfig;
 use tenant_ai::error::AppError;
 use tenant_ai::telemetry;
+use tenant_ai::workflows::apollo::ApolloNameMap;
 use tenant_ai::workflows::vacancy::applications::VacancyApplicationService;
+use tenant_ai::workflows::vacancy::{
+    AutomationDispatcher, BlueprintRegistry, FileVacancyRepository, InMemoryVacancyRepository,
+};
 use tracing::info;
 
 pub(crate) async fn run(mut args: ServeArgs) -> Result<(), AppError> {
@@ -35,13 +40,39 @@ pub(crate) async fn run(mut args: ServeArgs) -> Result<(), AppError> {
     let repository = Arc::new(InMemoryApplicationRepository::default());
     let alerts = Arc::new(InMemoryAlertPublisher::default());
     let evaluation_config = default_evaluation_config();
+    let automation: SharedAutomationDispatcher =
+        Arc::new(AutomationDispatcher::new(alerts.clone()));
     let application_service = Arc::new(VacancyApplicationService::new(
         repository,
         alerts,
         evaluation_config,
     ));
 
+    let vacancies: SharedVacancyRepository = match args.vacancy_store.take() {
+        Some(path) => Arc::new(FileVacancyRepository::open(path)?),
+        None => Arc::new(InMemoryVacancyRepository::new()),
+    };
+
+    let name_map: SharedApolloNameMap = match &config.apollo.name_map {
+        Some(path) => {
+            let name_map = ApolloNameMap::from_path(path)?;
+            let registry = BlueprintRegistry::shared_builtin();
+            name_map.validate(
+                registry
+                    .names()
+                    .filter_map(|name| registry.get(name))
+                    .flat_map(|blueprint| blueprint.task_templates())
+                    .map(|task| task.key.as_str()),
+            )?;
+            Arc::new(name_map)
+        }
+        None => Arc::new(ApolloNameMap::default()),
+    };
+
     let app = with_application_routes(application_service)
+        .layer(Extension(vacancies))
+        .layer(Extension(name_map))
+        .layer(Extension(automation))
         .layer(Extension(app_state))
         .layer(prometheus_layer);
 